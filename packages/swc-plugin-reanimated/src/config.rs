@@ -0,0 +1,93 @@
+use std::path::PathBuf;
+
+use serde_json::Value;
+use swc_plugin::source_map::FileName;
+use swc_reanimated_worklets_visitor::WorkletsOptions;
+
+/// Parses the plugin options passed through swc's `jsc.experimental.plugins` config,
+/// an empty config is treated as the default one.
+pub fn parse_config(config: &str) -> Result<WorkletsOptions, serde_json::Error> {
+    if config.trim().is_empty() {
+        return Ok(Default::default());
+    }
+
+    serde_json::from_str(config)
+}
+
+/// Applies the file being transformed from the host's transform context.
+/// Hosts providing the cwd in the transform context don't need `relativeCwd`,
+/// the context's cwd takes precedence over it.
+pub fn apply_transform_context(worklets_options: &mut WorkletsOptions, context: &Value) {
+    worklets_options.filename = match context["filename"].as_str() {
+        Some(filename) => FileName::Real(PathBuf::from(filename)),
+        None => FileName::Anon,
+    };
+
+    if let Some(cwd) = context["cwd"].as_str() {
+        worklets_options.relative_cwd = Some(PathBuf::from(cwd));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use swc_reanimated_worklets_visitor::{Rule, Severity};
+
+    use super::*;
+
+    #[test]
+    fn parses_empty_config_as_default() {
+        for config in ["", "  \n"] {
+            let worklets_options = parse_config(config).unwrap();
+            assert_eq!(worklets_options.custom_globals, None);
+            assert_eq!(worklets_options.rules, None);
+            assert!(!worklets_options.check_worklet_calls);
+        }
+    }
+
+    #[test]
+    fn parses_rules_by_id() {
+        let worklets_options =
+            parse_config(r#"{ "rules": { "non-worklet-call": "error" } }"#).unwrap();
+        assert_eq!(
+            worklets_options.rules.unwrap().get(&Rule::NonWorkletCall),
+            Some(&Severity::Error)
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_fields() {
+        let err = parse_config(r#"{ "customGlobal": ["foo"] }"#).unwrap_err();
+        assert!(err.to_string().contains("unknown field `customGlobal`"), "{}", err);
+    }
+
+    #[test]
+    fn rejects_unknown_rules() {
+        let err = parse_config(r#"{ "rules": { "non-worklet-calls": "error" } }"#).unwrap_err();
+        assert!(err.to_string().contains("Unknown rule `non-worklet-calls`"), "{}", err);
+    }
+
+    #[test]
+    fn prefers_context_cwd_over_relative_cwd() {
+        let mut worklets_options = parse_config(r#"{ "relativeCwd": "/config" }"#).unwrap();
+        apply_transform_context(
+            &mut worklets_options,
+            &json!({ "filename": "/project/App.js", "cwd": "/project" }),
+        );
+
+        assert_eq!(
+            worklets_options.filename,
+            FileName::Real(PathBuf::from("/project/App.js"))
+        );
+        assert_eq!(worklets_options.relative_cwd, Some(PathBuf::from("/project")));
+    }
+
+    #[test]
+    fn keeps_relative_cwd_without_context_cwd() {
+        let mut worklets_options = parse_config(r#"{ "relativeCwd": "/config" }"#).unwrap();
+        apply_transform_context(&mut worklets_options, &json!({}));
+
+        assert_eq!(worklets_options.filename, FileName::Anon);
+        assert_eq!(worklets_options.relative_cwd, Some(PathBuf::from("/config")));
+    }
+}
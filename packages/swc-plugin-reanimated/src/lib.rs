@@ -1,34 +1,47 @@
-use std::path::PathBuf;
+#![allow(clippy::not_unsafe_ptr_arg_deref)]
+
+mod config;
 
 use serde_json::Value;
-use swc_plugin::{ast::*, plugin_transform, source_map::FileName, TransformPluginProgramMetadata};
-use swc_reanimated_worklets_visitor::{create_worklets_visitor, WorkletsOptions};
+use swc_common::errors::{Handler, HANDLER};
+use swc_plugin::{
+    ast::*, environment::PluginDiagnosticsEmitter, plugin_transform,
+    TransformPluginProgramMetadata,
+};
+use swc_reanimated_worklets_visitor::create_worklets_visitor;
+
+use crate::config::{apply_transform_context, parse_config};
 
 #[plugin_transform]
 pub fn process(program: Program, metadata: TransformPluginProgramMetadata) -> Program {
     let context: Value = serde_json::from_str(&metadata.transform_context)
         .expect("Should able to deserialize context");
-    let filename = if let Some(filename) = (&context["filename"]).as_str() {
-        FileName::Real(PathBuf::from(filename))
-    } else {
-        FileName::Anon
-    };
-
-    let plugin_config: Option<Value> = serde_json::from_str(&metadata.plugin_config).ok();
-
-    let relative_cwd = if let Some(config) = plugin_config {
-        config["relativeCwd"]
-            .as_str()
-            .and_then(|v| Some(PathBuf::from(v.to_string())))
-    } else {
-        None
-    };
-
-    let visitor = create_worklets_visitor(
-        WorkletsOptions::new(None, filename, relative_cwd),
-        std::sync::Arc::new(metadata.source_map),
-        metadata.comments,
-    );
-
-    program.fold_with(&mut as_folder(visitor))
+
+    // The visitor reports diagnostics through swc_common's HANDLER,
+    // route those to the host the same way the plugin's own handler does.
+    let handler = Handler::with_emitter(true, false, Box::new(PluginDiagnosticsEmitter));
+
+    HANDLER.set(&handler, || {
+        let mut worklets_options = match parse_config(&metadata.plugin_config) {
+            Ok(worklets_options) => worklets_options,
+            Err(err) => {
+                handler
+                    .struct_err(&format!(
+                        "Invalid swc-plugin-reanimated config: {}\nconfig: {}",
+                        err, metadata.plugin_config
+                    ))
+                    .emit();
+                return program;
+            }
+        };
+        apply_transform_context(&mut worklets_options, &context);
+
+        let visitor = create_worklets_visitor(
+            worklets_options,
+            std::sync::Arc::new(metadata.source_map),
+            metadata.comments,
+        );
+
+        program.fold_with(&mut as_folder(visitor))
+    })
 }
@@ -0,0 +1,13 @@
+use std::path::PathBuf;
+
+use swc_reanimated_worklets_visitor::WorkletsOptions;
+
+/// Reads options from a JSON file, in the same shape as the plugin config
+/// so the config of an existing project can be reused as is.
+pub fn read_config(path: &PathBuf) -> Result<WorkletsOptions, String> {
+    let config = std::fs::read_to_string(path)
+        .map_err(|err| format!("Unable to read config `{}`: {}", path.display(), err))?;
+
+    serde_json::from_str(&config)
+        .map_err(|err| format!("Invalid config `{}`: {}", path.display(), err))
+}
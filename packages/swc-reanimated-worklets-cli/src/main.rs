@@ -0,0 +1,321 @@
+mod config;
+
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+    sync::Arc,
+};
+
+use clap::Parser as ClapParser;
+use swc_common::{
+    comments::SingleThreadedComments,
+    errors::{ColorConfig, Handler, HANDLER},
+    FileName, FilePathMapping, Globals, SourceMap, GLOBALS,
+};
+use swc_ecmascript::{
+    codegen::{text_writer::JsWriter, Emitter},
+    parser::{lexer::Lexer, EsConfig, Parser, StringInput, Syntax, TsConfig},
+    visit::VisitMutWith,
+};
+use swc_reanimated_worklets_visitor::{
+    create_worklets_visitor_with_report, JsxInWorklets, Rule, Severity, WorkletsOptions,
+    WorkletsReport, WorkletsReportCollector, WorkletFormat, WorkletsTarget,
+};
+use walkdir::WalkDir;
+
+use crate::config::read_config;
+
+/// Infix of the files written in directory mode, i.e. `App.tsx` -> `App.worklets.tsx`.
+const OUTPUT_INFIX: &str = "worklets";
+
+const EXTENSIONS: [&str; 6] = ["js", "jsx", "mjs", "cjs", "ts", "tsx"];
+
+/// Runs the reanimated worklets transform over a file and prints the transformed code
+/// with the worklet report. Given a directory, transforms every file in it and writes
+/// `<name>.worklets.<ext>` and `<name>.worklets.json` next to each input instead.
+#[derive(Debug, ClapParser)]
+#[clap(name = "swc-reanimated-worklets", version)]
+struct Args {
+    /// File or directory to transform.
+    input: PathBuf,
+    /// JSON file with options, in the same shape as the plugin config.
+    #[clap(long)]
+    config: Option<PathBuf>,
+    /// Directory `__location` paths are made relative to.
+    #[clap(long)]
+    relative_cwd: Option<PathBuf>,
+    /// Additional global available on the UI thread, can be repeated.
+    #[clap(long = "global")]
+    custom_globals: Vec<String>,
+    /// Additional side-effect free function, can be repeated.
+    #[clap(long = "pure-function")]
+    custom_pure_functions: Vec<String>,
+    /// Warn about worklets calling same-module functions which aren't worklets.
+    #[clap(long)]
+    check_worklet_calls: bool,
+    /// Report all warnings as errors.
+    #[clap(long)]
+    warnings_as_errors: bool,
+    /// Severity override as `<rule>=<off|warn|error>`, can be repeated.
+    #[clap(long = "rule", parse(try_from_str = parse_rule))]
+    rules: Vec<(Rule, Severity)>,
+    /// Transform for a development build, injecting runtime warnings.
+    #[clap(long)]
+    dev: bool,
+    /// Don't inject runtime warnings for `.value` reads in inline styles.
+    #[clap(long)]
+    disable_inline_styles_warning: bool,
+    /// Emit worklets for web builds, without the data only the native runtime needs.
+    #[clap(long)]
+    omit_native_only_data: bool,
+    /// Emit lightweight worklets for Reanimated's Jest mocks.
+    #[clap(long)]
+    test_mode: bool,
+    /// Compile JSX inside of worklets (`transform`) or report it (`error`).
+    #[clap(long)]
+    jsx_in_worklets: Option<JsxInWorklets>,
+    /// Engine worklets are stringified for (`default`, `legacy` or `modern`).
+    #[clap(long)]
+    target: Option<WorkletsTarget>,
+    /// Re-parse each generated worklet string, reporting broken ones as errors.
+    #[clap(long)]
+    validate_worklets: bool,
+    /// Compress and mangle worklets with swc's minifier, keeping captured names.
+    #[clap(long)]
+    mangle_worklets: bool,
+    /// Print worklet strings `minified`, `pretty` or `pretty-with-comments`.
+    #[clap(long)]
+    worklet_format: Option<WorkletFormat>,
+}
+
+fn parse_rule(value: &str) -> Result<(Rule, Severity), String> {
+    let (id, severity) = value
+        .split_once('=')
+        .ok_or_else(|| format!("Expected `<rule>=<severity>`, got `{}`", value))?;
+
+    Ok((id.parse()?, severity.parse()?))
+}
+
+/// Merges the flags into the config read from `--config`, flags taking precedence.
+fn make_config(args: &Args) -> Result<WorkletsOptions, String> {
+    let mut config = match &args.config {
+        Some(path) => read_config(path)?,
+        None => Default::default(),
+    };
+
+    if !args.custom_globals.is_empty() {
+        config
+            .custom_globals
+            .get_or_insert_with(Default::default)
+            .extend(args.custom_globals.iter().cloned());
+    }
+    if !args.custom_pure_functions.is_empty() {
+        config
+            .custom_pure_functions
+            .get_or_insert_with(Default::default)
+            .extend(args.custom_pure_functions.iter().cloned());
+    }
+    if !args.rules.is_empty() {
+        config
+            .rules
+            .get_or_insert_with(Default::default)
+            .extend(args.rules.iter().cloned());
+    }
+    if args.relative_cwd.is_some() {
+        config.relative_cwd = args.relative_cwd.clone();
+    }
+    config.check_worklet_calls |= args.check_worklet_calls;
+    config.warnings_as_errors |= args.warnings_as_errors;
+    config.dev |= args.dev;
+    config.disable_inline_styles_warning |= args.disable_inline_styles_warning;
+    config.omit_native_only_data |= args.omit_native_only_data;
+    config.test_mode |= args.test_mode;
+    config.validate_worklets |= args.validate_worklets;
+    config.mangle_worklets |= args.mangle_worklets;
+    if let Some(jsx_in_worklets) = args.jsx_in_worklets {
+        config.jsx_in_worklets = jsx_in_worklets;
+    }
+    if let Some(target) = args.target {
+        config.target = target;
+    }
+    if let Some(worklet_format) = args.worklet_format {
+        config.worklet_format = worklet_format;
+    }
+
+    Ok(config)
+}
+
+/// Output of transforming a single file.
+struct TransformOutput {
+    code: String,
+    report: WorkletsReport,
+    /// Whether any error diagnostics were emitted.
+    has_errors: bool,
+}
+
+fn syntax_for(path: &Path) -> Syntax {
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("ts") => Syntax::Typescript(TsConfig::default()),
+        Some("tsx") => Syntax::Typescript(TsConfig {
+            tsx: true,
+            ..Default::default()
+        }),
+        _ => Syntax::Es(EsConfig {
+            jsx: true,
+            ..Default::default()
+        }),
+    }
+}
+
+fn transform_file(path: &Path, config: &WorkletsOptions) -> Result<TransformOutput, String> {
+    let src = std::fs::read_to_string(path)
+        .map_err(|err| format!("Unable to read `{}`: {}", path.display(), err))?;
+
+    let source_map = Arc::new(SourceMap::new(FilePathMapping::empty()));
+    let handler =
+        Handler::with_tty_emitter(ColorConfig::Auto, true, false, Some(source_map.clone()));
+    let filename = FileName::Real(path.to_path_buf());
+    let source_file = source_map.new_source_file(filename.clone(), src);
+    let comments = SingleThreadedComments::default();
+
+    GLOBALS.set(&Globals::new(), || {
+        HANDLER.set(&handler, || {
+            let lexer = Lexer::new(
+                syntax_for(path),
+                Default::default(),
+                StringInput::from(&*source_file),
+                Some(&comments),
+            );
+            let mut parser = Parser::new_from(lexer);
+            let mut module = parser.parse_module().map_err(|err| {
+                err.into_diagnostic(&handler).emit();
+                format!("Unable to parse `{}`", path.display())
+            })?;
+
+            let collector = WorkletsReportCollector::new();
+            let worklets_options = WorkletsOptions {
+                filename,
+                ..config.clone()
+            };
+            let mut visitor = create_worklets_visitor_with_report(
+                worklets_options,
+                source_map.clone(),
+                comments.clone(),
+                collector.clone(),
+            );
+            module.visit_mut_with(&mut visitor);
+
+            let mut buf = vec![];
+            {
+                let mut emitter = Emitter {
+                    cfg: Default::default(),
+                    cm: source_map.clone(),
+                    comments: Some(&comments),
+                    wr: JsWriter::new(source_map.clone(), "\n", &mut buf, None),
+                };
+                emitter
+                    .emit_module(&module)
+                    .map_err(|err| format!("Unable to print `{}`: {}", path.display(), err))?;
+            }
+
+            Ok(TransformOutput {
+                code: String::from_utf8_lossy(&buf).to_string(),
+                report: collector.take(),
+                has_errors: handler.has_errors(),
+            })
+        })
+    })
+}
+
+/// Path the output for `path` is written to in directory mode, along with the report's.
+fn output_paths(path: &Path) -> (PathBuf, PathBuf) {
+    let stem = path.file_stem().and_then(|stem| stem.to_str()).unwrap_or_default();
+    let ext = path.extension().and_then(|ext| ext.to_str()).unwrap_or_default();
+
+    (
+        path.with_file_name(format!("{}.{}.{}", stem, OUTPUT_INFIX, ext)),
+        path.with_file_name(format!("{}.{}.json", stem, OUTPUT_INFIX)),
+    )
+}
+
+/// Checks if the file should be transformed in directory mode, skipping previous outputs.
+fn is_input_file(path: &Path) -> bool {
+    let is_source = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| EXTENSIONS.contains(&ext));
+    let is_output = path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .is_some_and(|stem| stem.ends_with(&format!(".{}", OUTPUT_INFIX)));
+
+    is_source && !is_output
+}
+
+fn run_file(path: &Path, config: &WorkletsOptions) -> Result<bool, String> {
+    let output = transform_file(path, config)?;
+    let report = serde_json::to_string_pretty(&output.report).map_err(|err| err.to_string())?;
+
+    println!("{}", output.code);
+    println!("{}", report);
+
+    Ok(!output.has_errors)
+}
+
+fn run_dir(dir: &Path, config: &WorkletsOptions) -> Result<bool, String> {
+    let mut succeeded = true;
+
+    let entries = WalkDir::new(dir)
+        .into_iter()
+        .filter_entry(|entry| entry.file_name() != "node_modules");
+    for entry in entries {
+        let entry = entry.map_err(|err| err.to_string())?;
+        let path = entry.path();
+        if !entry.file_type().is_file() || !is_input_file(path) {
+            continue;
+        }
+
+        // Keep going on failures, to report all of them in a single run.
+        let output = match transform_file(path, config) {
+            Ok(output) => output,
+            Err(err) => {
+                eprintln!("{}", err);
+                succeeded = false;
+                continue;
+            }
+        };
+        succeeded &= !output.has_errors;
+
+        let (code_path, report_path) = output_paths(path);
+        let report =
+            serde_json::to_string_pretty(&output.report).map_err(|err| err.to_string())?;
+        std::fs::write(&code_path, output.code)
+            .and_then(|_| std::fs::write(&report_path, report))
+            .map_err(|err| format!("Unable to write `{}`: {}", code_path.display(), err))?;
+
+        eprintln!("{} -> {}", path.display(), code_path.display());
+    }
+
+    Ok(succeeded)
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    let result = make_config(&args).and_then(|config| {
+        if args.input.is_dir() {
+            run_dir(&args.input, &config)
+        } else {
+            run_file(&args.input, &config)
+        }
+    });
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -19,7 +19,9 @@ pub static GESTURE_HANDLER_BUILDER_METHODS: [&str; 10] = [
     "onTouchesCancelled",
 ];
 
-pub static GESTURE_HANDLER_GESTURE_OBJECTS: [&str; 12] = [
+pub static GESTURE_HANDLER_MODULE: &str = "react-native-gesture-handler";
+
+pub static GESTURE_HANDLER_GESTURE_OBJECTS: [&str; 13] = [
     // from https://github.com/software-mansion/react-native-gesture-handler/blob/new-api/src/handlers/gestures/gestureObjects.ts
     "Tap",
     "Pan",
@@ -28,6 +30,7 @@ pub static GESTURE_HANDLER_GESTURE_OBJECTS: [&str; 12] = [
     "Fling",
     "LongPress",
     "ForceTouch",
+    "Hover",
     "Native",
     "Manual",
     "Race",
@@ -35,7 +38,105 @@ pub static GESTURE_HANDLER_GESTURE_OBJECTS: [&str; 12] = [
     "Exclusive",
 ];
 
-pub static GLOBALS: [&str; 54] = [
+pub static REANIMATED_MODULE: &str = "react-native-reanimated";
+
+pub static LAYOUT_ANIMATION_CALLBACK_METHODS: [&str; 1] = ["withCallback"];
+
+pub static LAYOUT_ANIMATION_OBJECTS: [&str; 87] = [
+    // from https://github.com/software-mansion/react-native-reanimated/tree/main/src/reanimated2/layoutReanimation
+    // builders
+    "BaseAnimationBuilder",
+    "ComplexAnimationBuilder",
+    "Keyframe",
+    // entry / exit animations
+    "BounceIn",
+    "BounceInDown",
+    "BounceInLeft",
+    "BounceInRight",
+    "BounceInUp",
+    "BounceOut",
+    "BounceOutDown",
+    "BounceOutLeft",
+    "BounceOutRight",
+    "BounceOutUp",
+    "FadeIn",
+    "FadeInDown",
+    "FadeInLeft",
+    "FadeInRight",
+    "FadeInUp",
+    "FadeOut",
+    "FadeOutDown",
+    "FadeOutLeft",
+    "FadeOutRight",
+    "FadeOutUp",
+    "FlipInEasyX",
+    "FlipInEasyY",
+    "FlipInXDown",
+    "FlipInXUp",
+    "FlipInYLeft",
+    "FlipInYRight",
+    "FlipOutEasyX",
+    "FlipOutEasyY",
+    "FlipOutXDown",
+    "FlipOutXUp",
+    "FlipOutYLeft",
+    "FlipOutYRight",
+    "LightSpeedInLeft",
+    "LightSpeedInRight",
+    "LightSpeedOutLeft",
+    "LightSpeedOutRight",
+    "PinwheelIn",
+    "PinwheelOut",
+    "RollInLeft",
+    "RollInRight",
+    "RollOutLeft",
+    "RollOutRight",
+    "RotateInDownLeft",
+    "RotateInDownRight",
+    "RotateInUpLeft",
+    "RotateInUpRight",
+    "RotateOutDownLeft",
+    "RotateOutDownRight",
+    "RotateOutUpLeft",
+    "RotateOutUpRight",
+    "SlideInDown",
+    "SlideInLeft",
+    "SlideInRight",
+    "SlideInUp",
+    "SlideOutDown",
+    "SlideOutLeft",
+    "SlideOutRight",
+    "SlideOutUp",
+    "StretchInX",
+    "StretchInY",
+    "StretchOutX",
+    "StretchOutY",
+    "ZoomIn",
+    "ZoomInDown",
+    "ZoomInEasyDown",
+    "ZoomInEasyUp",
+    "ZoomInLeft",
+    "ZoomInRight",
+    "ZoomInRotate",
+    "ZoomInUp",
+    "ZoomOut",
+    "ZoomOutDown",
+    "ZoomOutEasyDown",
+    "ZoomOutEasyUp",
+    "ZoomOutLeft",
+    "ZoomOutRight",
+    "ZoomOutRotate",
+    "ZoomOutUp",
+    // layout transitions
+    "Layout",
+    "FadingTransition",
+    "SequencedTransition",
+    "JumpingTransition",
+    "CurvedTransition",
+    "EntryExitTransition",
+];
+
+pub static GLOBALS: [&str; 60] = [
     "this",
     "console",
     "performance",
@@ -78,6 +179,14 @@ pub static GLOBALS: [&str; 54] = [
     "_removeShadowNodeFromRegistry",
     "RegExp",
     "Error",
+    // used by the swc helpers inlined into worklets by the compat passes
+    "TypeError",
+    "ReferenceError",
+    "Reflect",
+    "Proxy",
+    "Function",
+    // holds private class fields lowered by the `class-properties` compat pass
+    "WeakMap",
     "global",
     "_measure",
     "_scrollTo",
@@ -110,5 +219,18 @@ pub static FUNCTION_ARGS_TO_WORKLETIZE: Lazy<HashMap<&'static str, Vec<usize>>>
     ])
 });
 
+/// Functions returning a worklet created from their first argument.
+pub static WORKLET_FACTORY_FUNCTIONS: [&str; 2] = ["useWorkletCallback", "createWorklet"];
+
 pub static FUNCTIONLESS_FLAG: i32 = 0b00000001;
 pub static STATEMENTLESS_FLAG: i32 = 0b00000010;
+
+/// Hooks whose worklets get `__optimalization` flags, with the set of flags
+/// each hook's runtime implementation understands.
+pub static HOOK_OPTIMIZATION_FLAGS: Lazy<HashMap<&'static str, i32>> = Lazy::new(|| {
+    HashMap::from([
+        ("useAnimatedStyle", FUNCTIONLESS_FLAG | STATEMENTLESS_FLAG),
+        ("useAnimatedProps", FUNCTIONLESS_FLAG | STATEMENTLESS_FLAG),
+        ("useDerivedValue", FUNCTIONLESS_FLAG | STATEMENTLESS_FLAG),
+    ])
+});
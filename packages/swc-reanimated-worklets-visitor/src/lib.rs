@@ -1,11 +1,13 @@
 mod constants;
 use hash32::{FnvHasher, Hasher};
 use std::hash::Hash;
-pub use visitors::{WorkletsOptions, ReanimatedWorkletsVisitor};
+pub use report::{WorkletDetection, WorkletReport, WorkletsReport, WorkletsReportCollector};
+pub use utils::{CompatAssumptions, CompatPass, Rule, Severity, WorkletsTarget};
+pub use visitors::{JsxInWorklets, WorkletFormat, WorkletsOptions, ReanimatedWorkletsVisitor};
+mod report;
 mod utils;
 mod visitors;
 
-use crate::constants::GLOBALS;
 use swc_ecmascript::{
     ast::*,
     visit::VisitMut,
@@ -18,19 +20,6 @@ fn calculate_hash(value: &str) -> f64 {
     fnv.finish32() as f64
 }
 
-//TODO
-struct ClosureGenerator {}
-
-impl ClosureGenerator {
-    pub fn new() -> Self {
-        ClosureGenerator {}
-    }
-
-    pub fn add_path(&mut self) {
-        // not implemented
-    }
-}
-
 pub fn create_worklets_visitor<
     C: Clone + swc_common::comments::Comments,
     S: swc_common::SourceMapper + SourceMapperExt,
@@ -39,18 +28,19 @@ pub fn create_worklets_visitor<
     source_map: std::sync::Arc<S>,
     comments: C,
 ) -> impl VisitMut {
-    let mut globals_vec = GLOBALS.map(|v| v.to_string()).to_vec();
-
-    // allows adding custom globals such as host-functions
-    if let Some(custom_globals) = worklets_options.custom_globals {
-        globals_vec.extend(custom_globals);
-    };
+    ReanimatedWorkletsVisitor::new(worklets_options, source_map, comments)
+}
 
-    ReanimatedWorkletsVisitor::new(
-        source_map,
-        globals_vec,
-        worklets_options.filename,
-        worklets_options.relative_cwd,
-        comments,
-    )
+/// Same as `create_worklets_visitor`, additionally recording a report of the generated
+/// worklets into the given collector.
+pub fn create_worklets_visitor_with_report<
+    C: Clone + swc_common::comments::Comments,
+    S: swc_common::SourceMapper + SourceMapperExt,
+>(
+    worklets_options: WorkletsOptions,
+    source_map: std::sync::Arc<S>,
+    comments: C,
+    report: WorkletsReportCollector,
+) -> impl VisitMut {
+    ReanimatedWorkletsVisitor::new(worklets_options, source_map, comments).with_report(report)
 }
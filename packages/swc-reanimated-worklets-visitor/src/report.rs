@@ -0,0 +1,71 @@
+use std::sync::{Arc, Mutex};
+
+use serde::Serialize;
+
+/// How a function was detected as a worklet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WorkletDetection {
+    /// Function with a `'worklet'` directive.
+    Directive,
+    /// Function passed to a reanimated hook, i.e. `useAnimatedStyle(() => {})`.
+    Hook,
+    /// Callback of a gesture object, i.e. `Gesture.Pan().onStart(() => {})`.
+    Gesture,
+    /// Callback of a layout animation, i.e. `FadeIn.withCallback(() => {})`.
+    LayoutAnimation,
+    /// Same-module function passed by reference, i.e. `useAnimatedStyle(styleFn)`.
+    Reference,
+}
+
+/// Describes a single generated worklet.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkletReport {
+    /// Name of the worklet function, if it has one.
+    pub name: Option<String>,
+    /// Value of `__workletHash`, `None` if native-only data is omitted.
+    pub hash: Option<f64>,
+    /// Value of `__location`, `None` if native-only data is omitted.
+    pub location: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub detection: WorkletDetection,
+    /// Variables the worklet captures from its enclosing scopes.
+    pub captured_variables: Vec<String>,
+    /// Value of `__optimalization`, if calculated.
+    pub optimization_flags: Option<i32>,
+}
+
+/// Worklets generated by a single transform.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkletsReport {
+    pub worklets: Vec<WorkletReport>,
+}
+
+/// Shared handle the visitor records its report into, readable once the visitor ran.
+#[derive(Debug, Clone, Default)]
+pub struct WorkletsReportCollector {
+    inner: Arc<Mutex<WorkletsReport>>,
+}
+
+impl WorkletsReportCollector {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub(crate) fn add(&self, worklet: WorkletReport) {
+        if let Ok(mut report) = self.inner.lock() {
+            report.worklets.push(worklet);
+        }
+    }
+
+    /// Takes the collected report, leaving an empty one behind.
+    pub fn take(&self) -> WorkletsReport {
+        self.inner
+            .lock()
+            .map(|mut report| std::mem::take(&mut *report))
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,323 @@
+use std::str::FromStr;
+
+use serde::Deserialize;
+use swc_common::{chain, comments::NoopComments, util::take::Take, DUMMY_SP};
+use swc_ecma_transforms_base::helpers::inject_helpers;
+use swc_ecma_transforms_compat::{
+    es2015::{
+        arrow, classes, destructuring, parameters, shorthand, spread, template_literal,
+    },
+    es2016::exponentiation,
+    es2018::{object_rest_spread, object_rest_spread::Config as ObjectRestSpreadConfig},
+    es2020::{nullish_coalescing, opt_chaining, optional_chaining},
+    es2022::{class_properties, static_blocks},
+};
+use swc_ecmascript::{
+    ast::{BlockStmt, BlockStmtOrExpr, Expr, ExprStmt, ReturnStmt, Script, Stmt},
+    visit::{Fold, VisitMut, VisitMutWith},
+};
+
+/// JS engine worklets are stringified for, selecting the compat passes run over them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WorkletsTarget {
+    /// Same passes as the Babel plugin, for current Hermes and JSC.
+    #[default]
+    Default,
+    /// Additionally downlevels destructuring, spread, classes and exponentiation,
+    /// for engines without full ES2015 / ES2016 support.
+    Legacy,
+    /// No compat passes, for engines supporting ES2020, i.e. V8.
+    Modern,
+}
+
+impl FromStr for WorkletsTarget {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "default" => Ok(WorkletsTarget::Default),
+            "legacy" => Ok(WorkletsTarget::Legacy),
+            "modern" => Ok(WorkletsTarget::Modern),
+            _ => Err(format!(
+                "Unknown target `{}`, expected one of `default`, `legacy`, `modern`",
+                value
+            )),
+        }
+    }
+}
+
+/// Compat pass run over worklets before they're stringified.
+/// Passes always run in the order they're declared in, regardless of the configured order.
+///
+/// `classes`, `spread` and `destructuring` may rely on swc helpers, i.e. `_classCallCheck`,
+/// which are declared inside of the worklet by [InlineHelpers].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CompatPass {
+    /// Class fields and static blocks. Always runs when `classes` does, as it doesn't
+    /// handle either. Runs first, as static blocks are lowered to arrow functions.
+    ClassProperties,
+    Shorthand,
+    Arrow,
+    OptionalChaining,
+    NullishCoalescing,
+    TemplateLiteral,
+    Exponentiation,
+    Classes,
+    Spread,
+    /// Object rest and spread. Always runs when `parameters` or `destructuring` does,
+    /// as neither handles object rest patterns.
+    ObjectRestSpread,
+    /// Default and rest parameters, lowered before destructuring as it relies on
+    /// destructured parameters being moved into the function body.
+    Parameters,
+    Destructuring,
+}
+
+impl CompatPass {
+    /// Id the pass is configured with, i.e. `"compatPasses": ["optional-chaining"]`.
+    pub fn id(&self) -> &'static str {
+        match self {
+            CompatPass::ClassProperties => "class-properties",
+            CompatPass::Shorthand => "shorthand",
+            CompatPass::Arrow => "arrow",
+            CompatPass::OptionalChaining => "optional-chaining",
+            CompatPass::NullishCoalescing => "nullish-coalescing",
+            CompatPass::TemplateLiteral => "template-literal",
+            CompatPass::Exponentiation => "exponentiation",
+            CompatPass::Classes => "classes",
+            CompatPass::Spread => "spread",
+            CompatPass::ObjectRestSpread => "object-rest-spread",
+            CompatPass::Parameters => "parameters",
+            CompatPass::Destructuring => "destructuring",
+        }
+    }
+
+    pub fn for_target(target: WorkletsTarget) -> Vec<CompatPass> {
+        match target {
+            WorkletsTarget::Default => vec![
+                CompatPass::Shorthand,
+                CompatPass::Arrow,
+                CompatPass::OptionalChaining,
+                CompatPass::NullishCoalescing,
+                CompatPass::TemplateLiteral,
+            ],
+            WorkletsTarget::Legacy => vec![
+                CompatPass::ClassProperties,
+                CompatPass::Shorthand,
+                CompatPass::Arrow,
+                CompatPass::OptionalChaining,
+                CompatPass::NullishCoalescing,
+                CompatPass::TemplateLiteral,
+                CompatPass::Exponentiation,
+                CompatPass::Classes,
+                CompatPass::Spread,
+                CompatPass::ObjectRestSpread,
+                CompatPass::Parameters,
+                CompatPass::Destructuring,
+            ],
+            WorkletsTarget::Modern => vec![],
+        }
+    }
+}
+
+/// Assumptions the compat passes may make about the worklet's code to emit simpler output,
+/// named after Babel's `assumptions`.
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct CompatAssumptions {
+    pub no_document_all: bool,
+    pub pure_getters: bool,
+    pub ignore_to_primitive_hint: bool,
+    pub mutable_template_object: bool,
+    pub iterable_is_array: bool,
+    pub ignore_function_length: bool,
+    pub constant_super: bool,
+    pub no_class_calls: bool,
+    pub set_class_methods: bool,
+    pub super_is_callable_constructor: bool,
+}
+
+/// Compat passes run over worklets, configured by the target, or by an explicit list of
+/// passes overriding it.
+#[derive(Debug, Clone, Default)]
+pub struct CompatConfig {
+    pub target: WorkletsTarget,
+    pub passes: Option<Vec<CompatPass>>,
+    pub assumptions: CompatAssumptions,
+}
+
+impl CompatConfig {
+    /// Passes to run, in the order they run in.
+    pub fn passes(&self) -> Vec<CompatPass> {
+        let mut passes = self
+            .passes
+            .clone()
+            .unwrap_or_else(|| CompatPass::for_target(self.target));
+        if passes
+            .iter()
+            .any(|pass| matches!(pass, CompatPass::Parameters | CompatPass::Destructuring))
+        {
+            passes.push(CompatPass::ObjectRestSpread);
+        }
+        if passes.contains(&CompatPass::Classes) {
+            passes.push(CompatPass::ClassProperties);
+        }
+        passes.sort();
+        passes.dedup();
+        passes
+    }
+
+    pub fn make_passes(&self) -> Vec<Box<dyn VisitMut>> {
+        let assumptions = self.assumptions;
+        self.passes()
+            .into_iter()
+            .map(|pass| -> Box<dyn VisitMut> {
+                match pass {
+                    CompatPass::Shorthand => Box::new(shorthand()),
+                    CompatPass::Arrow => Box::new(arrow()),
+                    CompatPass::OptionalChaining => {
+                        Box::new(optional_chaining(opt_chaining::Config {
+                            no_document_all: assumptions.no_document_all,
+                            pure_getter: assumptions.pure_getters,
+                        }))
+                    }
+                    CompatPass::NullishCoalescing => {
+                        Box::new(nullish_coalescing(nullish_coalescing::Config {
+                            no_document_all: assumptions.no_document_all,
+                        }))
+                    }
+                    CompatPass::TemplateLiteral => {
+                        Box::new(ScriptPass(template_literal(template_literal::Config {
+                            ignore_to_primitive: assumptions.ignore_to_primitive_hint,
+                            mutable_template: assumptions.mutable_template_object,
+                        })))
+                    }
+                    CompatPass::Exponentiation => Box::new(exponentiation()),
+                    CompatPass::ClassProperties => Box::new(ScriptPass(chain!(
+                        static_blocks(),
+                        class_properties(
+                            None::<NoopComments>,
+                            class_properties::Config {
+                                constant_super: assumptions.constant_super,
+                                no_document_all: assumptions.no_document_all,
+                                ..Default::default()
+                            },
+                        ),
+                    ))),
+                    CompatPass::Classes => Box::new(classes(
+                        None::<NoopComments>,
+                        classes::Config {
+                            constant_super: assumptions.constant_super,
+                            no_class_calls: assumptions.no_class_calls,
+                            set_class_methods: assumptions.set_class_methods,
+                            super_is_callable_constructor: assumptions
+                                .super_is_callable_constructor,
+                        },
+                    )),
+                    CompatPass::Spread => Box::new(spread(spread::Config {
+                        loose: assumptions.iterable_is_array,
+                    })),
+                    CompatPass::ObjectRestSpread => {
+                        Box::new(ScriptPass(object_rest_spread(ObjectRestSpreadConfig {
+                            pure_getters: assumptions.pure_getters,
+                            ..Default::default()
+                        })))
+                    }
+                    CompatPass::Parameters => {
+                        Box::new(FoldPass(parameters(parameters::Config {
+                            ignore_function_length: assumptions.ignore_function_length,
+                        })))
+                    }
+                    CompatPass::Destructuring => {
+                        Box::new(destructuring(destructuring::Config {
+                            loose: assumptions.iterable_is_array,
+                        }))
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
+/// Runs a `Fold`-only pass over the worklet, which is always visited as an expression.
+struct FoldPass<F: Fold>(F);
+
+impl<F: Fold> VisitMut for FoldPass<F> {
+    fn visit_mut_expr(&mut self, expr: &mut Expr) {
+        *expr = self.0.fold_expr(expr.take());
+    }
+}
+
+/// Runs a pass which only handles whole programs over the worklet, wrapped in a script.
+/// Statements the pass declares next to the worklet, i.e. template objects, are moved
+/// into its body.
+pub struct ScriptPass<V: VisitMut>(pub V);
+
+impl<V: VisitMut> VisitMut for ScriptPass<V> {
+    fn visit_mut_expr(&mut self, expr: &mut Expr) {
+        let mut script = Script {
+            span: DUMMY_SP,
+            body: vec![Stmt::Expr(ExprStmt {
+                span: DUMMY_SP,
+                expr: Box::new(expr.take()),
+            })],
+            shebang: None,
+        };
+        script.visit_mut_with(&mut self.0);
+
+        if let Some(Stmt::Expr(ExprStmt { expr: visited, .. })) = script.body.pop() {
+            *expr = *visited;
+            prepend_to_worklet_body(expr, script.body);
+        }
+    }
+}
+
+/// Declares the swc helpers used by the compat passes at the top of the worklet's body,
+/// as the UI runtime has none of the helpers injected into the module.
+/// Helpers are tracked by the `HELPERS` the passes run with, which must not be the module's.
+pub struct InlineHelpers;
+
+impl VisitMut for InlineHelpers {
+    fn visit_mut_expr(&mut self, expr: &mut Expr) {
+        let mut script = Script {
+            span: DUMMY_SP,
+            body: vec![],
+            shebang: None,
+        };
+        script.visit_mut_with(&mut inject_helpers());
+
+        prepend_to_worklet_body(expr, script.body);
+    }
+}
+
+fn prepend_to_worklet_body(worklet: &mut Expr, stmts: Vec<Stmt>) {
+    if stmts.is_empty() {
+        return;
+    }
+
+    let body = match worklet {
+        Expr::Fn(fn_expr) => match &mut fn_expr.function.body {
+            Some(body) => body,
+            None => return,
+        },
+        Expr::Arrow(arrow_expr) => {
+            if let BlockStmtOrExpr::Expr(arg) = &mut arrow_expr.body {
+                arrow_expr.body = BlockStmtOrExpr::BlockStmt(BlockStmt {
+                    span: DUMMY_SP,
+                    stmts: vec![Stmt::Return(ReturnStmt {
+                        span: DUMMY_SP,
+                        arg: Some(arg.take()),
+                    })],
+                });
+            }
+            match &mut arrow_expr.body {
+                BlockStmtOrExpr::BlockStmt(body) => body,
+                BlockStmtOrExpr::Expr(..) => unreachable!(),
+            }
+        }
+        _ => return,
+    };
+    body.stmts.splice(0..0, stmts);
+}
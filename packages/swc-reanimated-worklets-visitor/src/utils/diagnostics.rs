@@ -0,0 +1,167 @@
+use std::{collections::HashMap, str::FromStr};
+
+use serde::{de::Error as _, Deserialize, Deserializer};
+use swc_common::{
+    errors::{DiagnosticId, HANDLER},
+    Span,
+};
+
+/// Severity a diagnostic is reported with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Off,
+    Warn,
+    Error,
+}
+
+impl FromStr for Severity {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "off" => Ok(Severity::Off),
+            "warn" => Ok(Severity::Warn),
+            "error" => Ok(Severity::Error),
+            _ => Err(format!(
+                "Unknown severity `{}`, expected one of `off`, `warn`, `error`",
+                value
+            )),
+        }
+    }
+}
+
+/// Diagnostics emitted by the visitor. The id of each rule is used to
+/// override its severity, i.e. `{ "rules": { "non-worklet-call": "error" } }`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rule {
+    /// A worklet can't be generated from the node.
+    WorkletGeneration,
+    /// Async and generator functions can't be worklets.
+    UnsupportedWorklet,
+    /// A function passed by reference can't be resolved to a same-module declaration.
+    UnresolvedWorkletReference,
+    /// A gesture callback has a 'worklet' directive, but the gesture runs on the JS thread.
+    WorkletOnJsThread,
+    /// The worklet location can't be made relative to the configured cwd.
+    WorkletLocation,
+    /// A worklet calls a same-module function which isn't a worklet.
+    NonWorkletCall,
+    /// A worklet contains JSX, which the UI runtime can't parse.
+    JsxInWorklet,
+    /// A generated worklet string doesn't parse, or references variables outside of
+    /// its closure and globals.
+    InvalidWorklet,
+}
+
+impl Rule {
+    pub const ALL: [Rule; 8] = [
+        Rule::WorkletGeneration,
+        Rule::UnsupportedWorklet,
+        Rule::UnresolvedWorkletReference,
+        Rule::WorkletOnJsThread,
+        Rule::WorkletLocation,
+        Rule::NonWorkletCall,
+        Rule::JsxInWorklet,
+        Rule::InvalidWorklet,
+    ];
+
+    pub fn id(&self) -> &'static str {
+        match self {
+            Rule::WorkletGeneration => "worklet-generation",
+            Rule::UnsupportedWorklet => "unsupported-worklet",
+            Rule::UnresolvedWorkletReference => "unresolved-worklet-reference",
+            Rule::WorkletOnJsThread => "worklet-on-js-thread",
+            Rule::WorkletLocation => "worklet-location",
+            Rule::NonWorkletCall => "non-worklet-call",
+            Rule::JsxInWorklet => "jsx-in-worklet",
+            Rule::InvalidWorklet => "invalid-worklet",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Rule> {
+        Rule::ALL.into_iter().find(|rule| rule.id() == id)
+    }
+
+    fn default_severity(&self) -> Severity {
+        match self {
+            Rule::WorkletGeneration
+            | Rule::UnsupportedWorklet
+            | Rule::JsxInWorklet
+            | Rule::InvalidWorklet => Severity::Error,
+            _ => Severity::Warn,
+        }
+    }
+}
+
+impl FromStr for Rule {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Rule::from_id(value).ok_or_else(|| {
+            format!(
+                "Unknown rule `{}`, expected one of {}",
+                value,
+                Rule::ALL
+                    .iter()
+                    .map(|rule| format!("`{}`", rule.id()))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for Rule {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(D::Error::custom)
+    }
+}
+
+/// Emits diagnostics through swc's handler with the configured severity.
+#[derive(Debug, Clone, Default)]
+pub struct Diagnostics {
+    warnings_as_errors: bool,
+    rules: HashMap<Rule, Severity>,
+}
+
+impl Diagnostics {
+    pub fn new(warnings_as_errors: bool, rules: HashMap<Rule, Severity>) -> Self {
+        Diagnostics {
+            warnings_as_errors,
+            rules,
+        }
+    }
+
+    /// Per-rule overrides take precedence over `warnings_as_errors`.
+    pub fn severity(&self, rule: Rule) -> Severity {
+        match self.rules.get(&rule) {
+            Some(severity) => *severity,
+            None => match rule.default_severity() {
+                Severity::Warn if self.warnings_as_errors => Severity::Error,
+                severity => severity,
+            },
+        }
+    }
+
+    /// Emits a diagnostic attached to the given span.
+    /// Does nothing if the visitor is running without a handler (i.e. in a bare unit test).
+    pub fn emit(&self, rule: Rule, span: Span, message: &str) {
+        if !HANDLER.is_set() {
+            return;
+        }
+
+        let code = DiagnosticId::Lint(rule.id().to_string());
+        match self.severity(rule) {
+            Severity::Off => {}
+            Severity::Warn => {
+                HANDLER.with(|handler| handler.struct_span_warn(span, message).code(code).emit())
+            }
+            Severity::Error => {
+                HANDLER.with(|handler| handler.struct_span_err(span, message).code(code).emit())
+            }
+        }
+    }
+}
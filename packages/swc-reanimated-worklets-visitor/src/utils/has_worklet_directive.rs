@@ -0,0 +1,28 @@
+use swc_ecmascript::ast::*;
+
+/// Checks if given fn-like node has a `'worklet';` directive in its body, without
+/// modifying it. Unlike `DirectiveFinderVisitor`, this does not strip the directive.
+pub fn has_worklet_directive(fn_like_expr: &Expr) -> bool {
+    let body = match fn_like_expr {
+        Expr::Arrow(ArrowExpr {
+            body: BlockStmtOrExpr::BlockStmt(body),
+            ..
+        }) => body,
+        Expr::Fn(FnExpr {
+            function: Function {
+                body: Some(body), ..
+            },
+            ..
+        }) => body,
+        _ => return false,
+    };
+
+    body.stmts.iter().any(|stmt| {
+        if let Stmt::Expr(ExprStmt { expr, .. }) = stmt {
+            if let Expr::Lit(Lit::Str(Str { value, .. })) = &**expr {
+                return value == "worklet";
+            }
+        }
+        false
+    })
+}
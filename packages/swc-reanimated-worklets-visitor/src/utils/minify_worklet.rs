@@ -0,0 +1,77 @@
+use swc_common::{sync::Lrc, Mark, SourceMap, SyntaxContext, DUMMY_SP};
+use swc_ecma_transforms_base::{fixer::fixer, hygiene::hygiene, resolver};
+use swc_ecmascript::{
+    ast::*,
+    minifier::{
+        optimize,
+        option::{terser::TerserCompressorOptions, ExtraOptions, MangleOptions, MinifyOptions},
+    },
+    visit::{VisitMut, VisitMutWith},
+};
+
+/// Compresses and mangles a worklet with swc's minifier before it's stringified.
+///
+/// The worklet is minified as the only declaration of a module, so its name is kept,
+/// as are references to captured variables and globals, which don't resolve to a binding
+/// inside of it. Identifiers are resolved by name, the same way the UI runtime will
+/// resolve the printed worklet.
+pub fn minify_worklet(worklet: &mut FnExpr) {
+    worklet.visit_mut_with(&mut ClearSyntaxContext);
+    let ident = match &worklet.ident {
+        Some(ident) => ident.clone(),
+        None => return,
+    };
+
+    let mut module = Module {
+        span: DUMMY_SP,
+        body: vec![ModuleItem::Stmt(Stmt::Decl(Decl::Fn(FnDecl {
+            ident,
+            declare: false,
+            function: worklet.function.clone(),
+        })))],
+        shebang: None,
+    };
+
+    let cm: Lrc<SourceMap> = Default::default();
+    let unresolved_mark = Mark::new();
+    let top_level_mark = Mark::new();
+    module.visit_mut_with(&mut resolver(unresolved_mark, top_level_mark, false));
+
+    let mut module = optimize(
+        module,
+        cm.clone(),
+        None,
+        None,
+        &MinifyOptions {
+            compress: Some(TerserCompressorOptions::default().into_config(cm)),
+            mangle: Some(MangleOptions {
+                top_level: false,
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+        &ExtraOptions {
+            unresolved_mark,
+            top_level_mark,
+        },
+    );
+    module.visit_mut_with(&mut hygiene());
+    module.visit_mut_with(&mut fixer(None));
+
+    if let Some(ModuleItem::Stmt(Stmt::Decl(Decl::Fn(FnDecl { ident, function, .. })))) =
+        module.body.pop()
+    {
+        worklet.ident = Some(ident);
+        worklet.function = function;
+    }
+}
+
+/// Drops the syntax contexts assigned to identifiers by the host's resolver and the
+/// compat passes, as the printed worklet resolves identifiers by name only.
+struct ClearSyntaxContext;
+
+impl VisitMut for ClearSyntaxContext {
+    fn visit_mut_ident(&mut self, ident: &mut Ident) {
+        ident.span = ident.span.with_ctxt(SyntaxContext::empty());
+    }
+}
@@ -1,4 +1,14 @@
 mod get_callee_expr_ident;
 pub use get_callee_expr_ident::*;
 mod scope;
-pub use scope::*;
\ No newline at end of file
+pub use scope::*;
+mod compat;
+pub use compat::*;
+mod diagnostics;
+pub use diagnostics::*;
+mod read_only_comments;
+pub use read_only_comments::*;
+mod has_worklet_directive;
+pub use has_worklet_directive::*;
+mod minify_worklet;
+pub use minify_worklet::*;
@@ -0,0 +1,49 @@
+use swc_common::{
+    comments::{Comment, Comments},
+    BytePos,
+};
+
+/// Gives the codegen access to comments without removing them, as it takes the comments
+/// it prints. Comments of a worklet are printed into its string, but have to be kept
+/// for the JS-side function as well.
+pub struct ReadOnlyComments<'a, C: Comments>(pub &'a C);
+
+impl<'a, C: Comments> Comments for ReadOnlyComments<'a, C> {
+    fn add_leading(&self, _: BytePos, _: Comment) {}
+
+    fn add_leading_comments(&self, _: BytePos, _: Vec<Comment>) {}
+
+    fn has_leading(&self, pos: BytePos) -> bool {
+        self.0.has_leading(pos)
+    }
+
+    fn move_leading(&self, _: BytePos, _: BytePos) {}
+
+    fn take_leading(&self, pos: BytePos) -> Option<Vec<Comment>> {
+        self.0.get_leading(pos)
+    }
+
+    fn get_leading(&self, pos: BytePos) -> Option<Vec<Comment>> {
+        self.0.get_leading(pos)
+    }
+
+    fn add_trailing(&self, _: BytePos, _: Comment) {}
+
+    fn add_trailing_comments(&self, _: BytePos, _: Vec<Comment>) {}
+
+    fn has_trailing(&self, pos: BytePos) -> bool {
+        self.0.has_trailing(pos)
+    }
+
+    fn move_trailing(&self, _: BytePos, _: BytePos) {}
+
+    fn take_trailing(&self, pos: BytePos) -> Option<Vec<Comment>> {
+        self.0.get_trailing(pos)
+    }
+
+    fn get_trailing(&self, pos: BytePos) -> Option<Vec<Comment>> {
+        self.0.get_trailing(pos)
+    }
+
+    fn add_pure_comment(&self, _: BytePos) {}
+}
@@ -1,5 +1,4 @@
 use indexmap::IndexMap;
-use swc_common::Mark;
 use std::cell::RefCell;
 
 use swc_ecmascript::{
@@ -7,23 +6,17 @@ use swc_ecmascript::{
 };
 
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ScopeKind {
     Block,
+    #[default]
     Fn,
 }
 
-impl Default for ScopeKind {
-    fn default() -> Self {
-        ScopeKind::Fn
-    }
-}
-
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum IdentType {
     Binding,
     Ref,
-    Label,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -42,19 +35,13 @@ pub struct VarInfo {
 pub struct Scope<'a> {
     /// Parent scope of the scope
     pub parent: Option<&'a Scope<'a>>,
-    /// [Mark] of the current scope.
-    mark: Mark,
-    /// Kind of the scope.
-    kind: ScopeKind,
     pub bindings: IndexMap<Id, VarInfo, ahash::RandomState>,
 }
 
 impl<'a> Scope<'a> {
-    pub fn new(kind: ScopeKind, mark: Mark, parent: Option<&'a Scope<'a>>) -> Self {
+    pub fn new(parent: Option<&'a Scope<'a>>) -> Self {
         Scope {
             parent,
-            kind,
-            mark,
             bindings: Default::default(),
         }
     }
@@ -1,5 +1,5 @@
 use std::collections::HashSet;
-use swc_common::Mark;
+use swc_common::DUMMY_SP;
 use swc_ecmascript::{
   ast::*,
   visit::{Visit, VisitWith},
@@ -9,6 +9,11 @@ use crate::utils::{IdentType, Scope, ScopeKind, VarType, VarInfo};
 
 pub struct ClosureIdentVisitor<'a> {
   outputs: HashSet<Ident>,
+  /// Identifiers referenced in the worklet but declared outside of it.
+  pub captures: Vec<Ident>,
+  /// Subset of `captures` called directly, i.e. `foo()`.
+  pub captured_callees: Vec<Ident>,
+  var_kind: Option<VarDeclKind>,
   is_parent_member_expr: bool,
   is_parent_member_expr_computed: bool,
   is_in_object_expression: bool,
@@ -26,6 +31,9 @@ impl<'a> ClosureIdentVisitor<'a> {
   pub fn new(current: Scope<'a>, globals: &'a Vec<String>, fn_name: &'a Option<Ident>) -> Self {
       ClosureIdentVisitor {
           outputs: Default::default(),
+          captures: Default::default(),
+          captured_callees: Default::default(),
+          var_kind: None,
           is_parent_member_expr: false,
           is_parent_member_expr_computed: false,
           is_in_object_expression: false,
@@ -43,6 +51,9 @@ impl<'a> ClosureIdentVisitor<'a> {
   pub fn from(value: &ClosureIdentVisitor<'a>, current: Scope<'a>) -> Self {
       ClosureIdentVisitor {
           outputs: value.outputs.clone(),
+          captures: Default::default(),
+          captured_callees: Default::default(),
+          var_kind: value.var_kind,
           is_parent_member_expr: value.is_parent_member_expr,
           is_parent_member_expr_computed: value.is_parent_member_expr_computed,
           is_in_object_expression: value.is_in_object_expression,
@@ -50,7 +61,7 @@ impl<'a> ClosureIdentVisitor<'a> {
           parent_member_expr_prop_ident: value.parent_member_expr_prop_ident.clone(),
           parent_object_prop_ident: value.parent_object_prop_ident.clone(),
           scope: current,
-          ident_type: value.ident_type.clone(),
+          ident_type: value.ident_type,
           in_type: false,
           globals: value.globals,
           fn_name: value.fn_name,
@@ -58,13 +69,74 @@ impl<'a> ClosureIdentVisitor<'a> {
   }
 
   fn visit_stmt_within_child_scope(&mut self, s: &Stmt) {
-      let child_mark = Mark::fresh(Mark::root());
       let mut child = ClosureIdentVisitor::from(
           self,
-          Scope::new(ScopeKind::Block, child_mark, Some(&self.scope)),
+          Scope::new(Some(&self.scope)),
       );
 
-      child.visit_stmt_within_same_scope(s)
+      child.visit_stmt_within_same_scope(s);
+      let (captures, captured_callees) = (child.captures, child.captured_callees);
+      self.extend_captures(captures, captured_callees);
+  }
+
+  fn extend_captures(&mut self, captures: Vec<Ident>, captured_callees: Vec<Ident>) {
+      for ident in captures {
+          self.add_capture(&ident);
+      }
+      self.captured_callees.extend(captured_callees);
+  }
+
+  fn add_capture(&mut self, ident: &Ident) {
+      if !self.captures.iter().any(|capture| capture.to_id() == ident.to_id()) {
+          self.captures.push(ident.clone());
+      }
+  }
+
+  /// JSX is compiled to `React.createElement` calls, referencing `React` in the worklet.
+  fn visit_jsx_pragma(&mut self) {
+      let old = self.ident_type;
+      self.ident_type = Some(IdentType::Ref);
+      self.visit_ident(&Ident::new("React".into(), DUMMY_SP));
+      self.ident_type = old;
+  }
+
+  fn add_binding(&mut self, ident: &Ident) {
+      let kind = match self.var_kind {
+          Some(var_kind) => VarType::Var(var_kind),
+          None => VarType::Param,
+      };
+
+      self.scope.bindings.insert(
+          ident.to_id(),
+          VarInfo {
+              kind,
+              value: Default::default(),
+          },
+      );
+  }
+
+  /// Returns true if the identifier refers to a binding declared outside of the worklet.
+  fn is_captured(&self, ident: &Ident) -> bool {
+      if let Some(fn_name) = self.fn_name {
+          if fn_name == ident {
+              return false;
+          }
+      }
+
+      if self.globals.iter().any(|v| &*ident.sym == v) {
+          return false;
+      }
+
+      let mut current_scope = Some(&self.scope);
+      while let Some(scope) = current_scope {
+          if scope.bindings.contains_key(&ident.to_id()) {
+              return false;
+          }
+
+          current_scope = scope.parent;
+      }
+
+      true
   }
 
   fn visit_stmt_within_same_scope(&mut self, s: &Stmt) {
@@ -76,29 +148,31 @@ impl<'a> ClosureIdentVisitor<'a> {
       }
   }
 
-  fn visit_with_child<T>(&mut self, kind: ScopeKind, child_mark: Mark, node: &T)
+  fn visit_with_child<T>(&mut self, kind: ScopeKind, node: &T)
   where
       T: 'static + for<'any> VisitWith<ClosureIdentVisitor<'any>>,
   {
-      self.with_child(kind, child_mark, |child| {
+      self.with_child(kind, |child| {
           node.visit_children_with(child);
       });
   }
 
-  fn with_child<F>(&mut self, kind: ScopeKind, child_mark: Mark, op: F)
+  fn with_child<F>(&mut self, kind: ScopeKind, op: F)
   where
       F: for<'any> FnOnce(&mut ClosureIdentVisitor<'any>),
   {
-      let bindings = {
+      let (bindings, captures, captured_callees) = {
           let mut child =
-              ClosureIdentVisitor::from(self, Scope::new(kind, child_mark, Some(&self.scope)));
+              ClosureIdentVisitor::from(self, Scope::new(Some(&self.scope)));
 
           op(&mut child);
 
-          child.scope.bindings
+          (child.scope.bindings, child.captures, child.captured_callees)
       };
 
-      if !matches!(kind, ScopeKind::Fn { .. }) {
+      self.extend_captures(captures, captured_callees);
+
+      if !matches!(kind, ScopeKind::Fn) {
           let v = bindings;
 
           for (id, v) in v.into_iter().filter_map(|(id, v)| {
@@ -121,9 +195,9 @@ impl<'a> Visit for ClosureIdentVisitor<'a> {
   fn visit_member_expr(&mut self, member_expr: &MemberExpr) {
       let old_computed = self.is_parent_member_expr_computed;
       let old = self.is_parent_member_expr;
-      if let MemberProp::Computed(..) = member_expr.prop {
-          self.is_parent_member_expr_computed = true;
-      }
+      // Nested member expressions, i.e. `a.b.c`, restore the outer prop once visited.
+      let old_prop_ident = self.parent_member_expr_prop_ident.take();
+      self.is_parent_member_expr_computed = matches!(member_expr.prop, MemberProp::Computed(..));
 
       if let MemberProp::Ident(ident) = &member_expr.prop {
           self.parent_member_expr_prop_ident = Some(ident.clone())
@@ -132,16 +206,15 @@ impl<'a> Visit for ClosureIdentVisitor<'a> {
       self.is_parent_member_expr = true;
       member_expr.visit_children_with(self);
 
-      self.parent_member_expr_prop_ident = None;
+      self.parent_member_expr_prop_ident = old_prop_ident;
       self.is_parent_member_expr = old;
       self.is_parent_member_expr_computed = old_computed;
   }
 
   fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr) {
-      let child_mark = Mark::fresh(Mark::root());
-
-      self.with_child(ScopeKind::Fn, child_mark, |folder| {
+      self.with_child(ScopeKind::Fn, |folder| {
           let old = folder.ident_type;
+          folder.var_kind = None;
           folder.ident_type = Some(IdentType::Binding);
           arrow_expr.params.visit_with(folder);
           folder.ident_type = old;
@@ -172,15 +245,12 @@ impl<'a> Visit for ClosureIdentVisitor<'a> {
   }
 
   fn visit_block_stmt(&mut self, block: &BlockStmt) {
-      let child_mark = Mark::fresh(Mark::root());
-      self.visit_with_child(ScopeKind::Block, child_mark, block);
+      self.visit_with_child(ScopeKind::Block, block);
   }
 
   fn visit_catch_clause(&mut self, c: &CatchClause) {
-      let child_mark = Mark::fresh(Mark::root());
-
       // Child folder
-      self.with_child(ScopeKind::Fn, child_mark, |folder| {
+      self.with_child(ScopeKind::Fn, |folder| {
           folder.ident_type = Some(IdentType::Binding);
           c.param.visit_with(folder);
           folder.ident_type = Some(IdentType::Ref);
@@ -191,11 +261,10 @@ impl<'a> Visit for ClosureIdentVisitor<'a> {
 
   fn visit_class_decl(&mut self, n: &ClassDecl) {
       n.class.decorators.visit_with(self);
+      self.add_binding(&n.ident);
 
       // Create a child scope. The class name is only accessible within the class.
-      let child_mark = Mark::fresh(Mark::root());
-
-      self.with_child(ScopeKind::Fn, child_mark, |folder| {
+      self.with_child(ScopeKind::Fn, |folder| {
           folder.ident_type = Some(IdentType::Ref);
 
           n.class.visit_with(folder);
@@ -204,9 +273,7 @@ impl<'a> Visit for ClosureIdentVisitor<'a> {
 
   fn visit_class_expr(&mut self, n: &ClassExpr) {
       // Create a child scope. The class name is only accessible within the class.
-      let child_mark = Mark::fresh(Mark::root());
-
-      self.with_child(ScopeKind::Fn, child_mark, |folder| {
+      self.with_child(ScopeKind::Fn, |folder| {
           folder.ident_type = Some(IdentType::Binding);
           n.ident.visit_with(folder);
           folder.ident_type = Some(IdentType::Ref);
@@ -223,17 +290,13 @@ impl<'a> Visit for ClosureIdentVisitor<'a> {
       }
 
       {
-          let child_mark = Mark::fresh(Mark::root());
-
-          self.with_child(ScopeKind::Fn, child_mark, |child| {
+          self.with_child(ScopeKind::Fn, |child| {
               m.function.visit_with(child);
           });
       }
   }
 
   fn visit_constructor(&mut self, c: &Constructor) {
-      let child_mark = Mark::fresh(Mark::root());
-
       for p in c.params.iter() {
           match p {
               ParamOrTsParamProp::TsParamProp(p) => {
@@ -245,21 +308,16 @@ impl<'a> Visit for ClosureIdentVisitor<'a> {
           }
       }
 
-      {
-          let old = self.ident_type;
-          self.ident_type = Some(IdentType::Binding);
-          self.with_child(ScopeKind::Fn, child_mark, |folder| {
-              c.params.visit_with(folder);
-          });
-          self.ident_type = old;
+      // Params are bound in the scope of the body, which references them.
+      self.with_child(ScopeKind::Fn, |folder| {
+          folder.ident_type = Some(IdentType::Binding);
+          c.params.visit_with(folder);
 
-          self.with_child(ScopeKind::Fn, child_mark, |folder| match &c.body {
-              Some(body) => {
-                  body.visit_children_with(folder);
-              }
-              None => {}
-          });
-      }
+          folder.ident_type = Some(IdentType::Ref);
+          if let Some(body) = &c.body {
+              body.visit_children_with(folder);
+          }
+      });
   }
 
   fn visit_export_default_decl(&mut self, e: &ExportDefaultDecl) {
@@ -268,9 +326,7 @@ impl<'a> Visit for ClosureIdentVisitor<'a> {
       match &e.decl {
           DefaultDecl::Fn(f) => {
               if f.ident.is_some() {
-                  let child_mark = Mark::fresh(Mark::root());
-
-                  self.with_child(ScopeKind::Fn, child_mark, |folder| {
+                  self.with_child(ScopeKind::Fn, |folder| {
                       f.function.visit_with(folder)
                   })
               } else {
@@ -293,13 +349,12 @@ impl<'a> Visit for ClosureIdentVisitor<'a> {
   }
 
   fn visit_fn_decl(&mut self, node: &FnDecl) {
-      // We don't fold this as Hoister handles this.
+      // Name is bound in `visit_stmts`, as function declarations are hoisted.
 
       node.function.decorators.visit_with(self);
 
       {
-          let child_mark = Mark::fresh(Mark::root());
-          self.with_child(ScopeKind::Fn, child_mark, |folder| {
+          self.with_child(ScopeKind::Fn, |folder| {
               node.function.visit_with(folder);
           });
       }
@@ -308,51 +363,39 @@ impl<'a> Visit for ClosureIdentVisitor<'a> {
   fn visit_fn_expr(&mut self, e: &FnExpr) {
       e.function.decorators.visit_with(self);
 
-      let child_mark = Mark::fresh(Mark::root());
-      self.with_child(ScopeKind::Fn, child_mark, |folder| {
+      self.with_child(ScopeKind::Fn, |folder| {
           e.function.visit_with(folder);
       });
   }
 
   fn visit_for_in_stmt(&mut self, n: &ForInStmt) {
-      let child_mark = Mark::fresh(Mark::root());
-
-      self.with_child(ScopeKind::Block, child_mark, |child| {
+      self.with_child(ScopeKind::Block, |child| {
           n.left.visit_with(child);
           n.right.visit_with(child);
 
-          child.visit_stmt_within_child_scope(&*n.body);
+          child.visit_stmt_within_child_scope(&n.body);
       });
   }
 
   fn visit_for_of_stmt(&mut self, n: &ForOfStmt) {
-      let child_mark = Mark::fresh(Mark::root());
-
-      self.with_child(ScopeKind::Block, child_mark, |child| {
+      self.with_child(ScopeKind::Block, |child| {
           n.left.visit_with(child);
           n.right.visit_with(child);
 
-          child.visit_stmt_within_child_scope(&*n.body);
+          child.visit_stmt_within_child_scope(&n.body);
       });
   }
 
   fn visit_for_stmt(&mut self, n: &ForStmt) {
-      let child_mark = Mark::fresh(Mark::root());
-
-      self.ident_type = Some(IdentType::Binding);
-      self.with_child(ScopeKind::Block, child_mark, |child| {
+      // bindings declared in `init` are visible in the rest of the statement
+      self.with_child(ScopeKind::Block, |child| {
+          child.ident_type = Some(IdentType::Binding);
           n.init.visit_with(child);
-      });
 
-      self.ident_type = Some(IdentType::Ref);
-      self.with_child(ScopeKind::Block, child_mark, |child| {
+          child.ident_type = Some(IdentType::Ref);
           n.test.visit_with(child);
-      });
-
-      self.ident_type = Some(IdentType::Ref);
-      self.with_child(ScopeKind::Block, child_mark, |child| {
           n.update.visit_with(child);
-          child.visit_stmt_within_child_scope(&*n.body);
+          child.visit_stmt_within_child_scope(&n.body);
       });
   }
 
@@ -368,12 +411,9 @@ impl<'a> Visit for ClosureIdentVisitor<'a> {
       f.return_type.visit_with(self);
 
       self.ident_type = Some(IdentType::Ref);
-      match &f.body {
-          Some(body) => {
-              // Prevent creating new scope.
-              body.visit_children_with(self);
-          }
-          None => {}
+      if let Some(body) = &f.body {
+          // Prevent creating new scope.
+          body.visit_children_with(self);
       }
   }
 
@@ -392,25 +432,33 @@ impl<'a> Visit for ClosureIdentVisitor<'a> {
       self.ident_type = old;
   }
 
+  // Labels aren't variables, so only the labeled statement is visited.
+  fn visit_labeled_stmt(&mut self, s: &LabeledStmt) {
+      s.body.visit_with(self);
+  }
+
+  // Private names, i.e. `#count`, are class members rather than variables.
+  fn visit_private_name(&mut self, _: &PrivateName) {}
+
+  fn visit_break_stmt(&mut self, _: &BreakStmt) {}
+
+  fn visit_continue_stmt(&mut self, _: &ContinueStmt) {}
+
   fn visit_method_prop(&mut self, m: &MethodProp) {
       m.key.visit_with(self);
 
       {
-          let child_mark = Mark::fresh(Mark::root());
-
-          self.with_child(ScopeKind::Fn, child_mark, |child| {
+          self.with_child(ScopeKind::Fn, |child| {
               m.function.visit_with(child);
           });
       };
   }
 
   fn visit_object_lit(&mut self, object_expr: &ObjectLit) {
-      let child_mark = Mark::fresh(Mark::root());
-
-      let bindings = {
+      let (bindings, captures, captured_callees) = {
           let mut child = ClosureIdentVisitor::from(
               self,
-              Scope::new(ScopeKind::Fn, child_mark, Some(&self.scope)),
+              Scope::new(Some(&self.scope)),
           );
 
           let old_in_object_expression = child.is_in_object_expression;
@@ -429,7 +477,8 @@ impl<'a> Visit for ClosureIdentVisitor<'a> {
                               child.parent_object_prop_ident = Some(ident.clone());
                               prop.visit_children_with(&mut child);
                           }
-                          Prop::KeyValue(KeyValueProp { value, .. }) => {
+                          Prop::KeyValue(KeyValueProp { key, value }) => {
+                              key.visit_with(&mut child);
                               value.visit_children_with(&mut child);
                           }
                           _ => {
@@ -446,10 +495,12 @@ impl<'a> Visit for ClosureIdentVisitor<'a> {
 
           child.is_in_object_expression = old_in_object_expression;
 
-          child.scope.bindings
+          (child.scope.bindings, child.captures, child.captured_callees)
       };
 
-      if !matches!(ScopeKind::Fn, ScopeKind::Fn { .. }) {
+      self.extend_captures(captures, captured_callees);
+
+      if !matches!(ScopeKind::Fn, ScopeKind::Fn) {
           let v = bindings;
 
           for (id, v) in v.into_iter().filter_map(|(id, v)| {
@@ -467,8 +518,10 @@ impl<'a> Visit for ClosureIdentVisitor<'a> {
   }
 
   fn visit_param(&mut self, param: &Param) {
+      let old_var_kind = self.var_kind.take();
       self.ident_type = Some(IdentType::Binding);
       param.visit_children_with(self);
+      self.var_kind = old_var_kind;
   }
 
   fn visit_assign_pat(&mut self, node: &AssignPat) {
@@ -484,9 +537,7 @@ impl<'a> Visit for ClosureIdentVisitor<'a> {
       m.key.visit_with(self);
 
       {
-          let child_mark = Mark::fresh(Mark::root());
-
-          self.with_child(ScopeKind::Fn, child_mark, |child| {
+          self.with_child(ScopeKind::Fn, |child| {
               m.function.visit_with(child);
           });
       }
@@ -496,9 +547,7 @@ impl<'a> Visit for ClosureIdentVisitor<'a> {
       n.key.visit_with(self);
 
       {
-          let child_mark = Mark::fresh(Mark::root());
-
-          self.with_child(ScopeKind::Fn, child_mark, |child| {
+          self.with_child(ScopeKind::Fn, |child| {
               child.ident_type = Some(IdentType::Binding);
               n.param.visit_with(child);
               n.body.visit_with(child);
@@ -509,14 +558,59 @@ impl<'a> Visit for ClosureIdentVisitor<'a> {
   fn visit_switch_stmt(&mut self, s: &SwitchStmt) {
       s.discriminant.visit_with(self);
 
-      let child_mark = Mark::fresh(Mark::root());
-
-      self.with_child(ScopeKind::Block, child_mark, |folder| {
+      self.with_child(ScopeKind::Block, |folder| {
           s.cases.visit_with(folder);
       });
   }
 
+  fn visit_stmts(&mut self, stmts: &[Stmt]) {
+      // function declarations are hoisted, so they can be called before being declared
+      for stmt in stmts {
+          if let Stmt::Decl(Decl::Fn(fn_decl)) = stmt {
+              let old = self.var_kind;
+              self.var_kind = Some(VarDeclKind::Let);
+              self.add_binding(&fn_decl.ident);
+              self.var_kind = old;
+          }
+      }
+
+      for stmt in stmts {
+          stmt.visit_with(self);
+      }
+  }
+
+  fn visit_var_decl(&mut self, var_decl: &VarDecl) {
+      let old = self.var_kind;
+      self.var_kind = Some(var_decl.kind);
+      var_decl.visit_children_with(self);
+      self.var_kind = old;
+  }
+
+  fn visit_prop_name(&mut self, prop_name: &PropName) {
+      // only computed keys reference other bindings, i.e. `{ [key]: value }`
+      if let PropName::Computed(computed) = prop_name {
+          computed.visit_with(self);
+      }
+  }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+      if let Callee::Expr(callee) = &call_expr.callee {
+          if let Expr::Ident(ident) = callee.unwrap_parens() {
+              if self.is_captured(ident) {
+                  self.captured_callees.push(ident.clone());
+              }
+          }
+      }
+
+      call_expr.visit_children_with(self);
+  }
+
   fn visit_ident(&mut self, ident: &Ident) {
+      if self.ident_type == Some(IdentType::Binding) {
+          self.add_binding(ident);
+          return;
+      }
+
       if let Some(fn_name) = self.fn_name {
           if fn_name == ident {
               return;
@@ -554,8 +648,8 @@ impl<'a> Visit for ClosureIdentVisitor<'a> {
                   current_scope = scope.parent;
               }
 
+              self.add_capture(ident);
               /* TODO
-              closure.set(name, path.node);
               closureGenerator.addPath(name, path);
               */
           }
@@ -576,6 +670,8 @@ impl<'a> Visit for ClosureIdentVisitor<'a> {
               }
           }
       }
+
+      assign_expr.visit_children_with(self);
   }
 
   fn visit_var_declarator(&mut self, decl: &VarDeclarator) {
@@ -586,6 +682,88 @@ impl<'a> Visit for ClosureIdentVisitor<'a> {
       decl.name.visit_with(self);
       self.ident_type = old_type;
 
+      let old_var_kind = self.var_kind.take();
       decl.init.visit_children_with(self);
+      self.var_kind = old_var_kind;
+  }
+
+  // Captures are computed from the worklet as written, so JSX and TypeScript are
+  // analyzed as the code they're compiled to in the worklet string.
+
+  fn visit_jsx_element(&mut self, element: &JSXElement) {
+      self.visit_jsx_pragma();
+      element.visit_children_with(self);
   }
+
+  fn visit_jsx_fragment(&mut self, fragment: &JSXFragment) {
+      self.visit_jsx_pragma();
+      fragment.visit_children_with(self);
+  }
+
+  fn visit_jsx_element_name(&mut self, name: &JSXElementName) {
+      match name {
+          // `<view />` is a host element, only `<View />` refers to a component.
+          JSXElementName::Ident(ident) => {
+              if !ident.sym.starts_with(|c: char| c.is_ascii_lowercase()) {
+                  ident.visit_with(self);
+              }
+          }
+          JSXElementName::JSXMemberExpr(member_expr) => member_expr.visit_with(self),
+          JSXElementName::JSXNamespacedName(..) => {}
+      }
+  }
+
+  fn visit_jsx_member_expr(&mut self, member_expr: &JSXMemberExpr) {
+      member_expr.obj.visit_with(self);
+  }
+
+  fn visit_jsx_closing_element(&mut self, _: &JSXClosingElement) {}
+
+  fn visit_jsx_attr_name(&mut self, _: &JSXAttrName) {}
+
+  fn visit_ts_enum_decl(&mut self, n: &TsEnumDecl) {
+      let old = self.var_kind;
+      self.var_kind = Some(VarDeclKind::Let);
+      self.add_binding(&n.id);
+
+      // Members can be referenced by name from the initializers of other members.
+      self.with_child(ScopeKind::Block, |folder| {
+          for member in &n.members {
+              if let TsEnumMemberId::Ident(ident) = &member.id {
+                  folder.add_binding(ident);
+              }
+          }
+
+          folder.ident_type = Some(IdentType::Ref);
+          for member in &n.members {
+              member.init.visit_with(folder);
+          }
+      });
+      self.var_kind = old;
+  }
+
+  fn visit_ts_module_decl(&mut self, n: &TsModuleDecl) {
+      if let TsModuleName::Ident(ident) = &n.id {
+          let old = self.var_kind;
+          self.var_kind = Some(VarDeclKind::Let);
+          self.add_binding(ident);
+          self.var_kind = old;
+      }
+
+      self.with_child(ScopeKind::Fn, |folder| {
+          n.body.visit_with(folder);
+      });
+  }
+
+  // Types are stripped from the worklet string, so they never reference its closure.
+
+  fn visit_ts_type(&mut self, _: &TsType) {}
+
+  fn visit_ts_type_param_decl(&mut self, _: &TsTypeParamDecl) {}
+
+  fn visit_ts_expr_with_type_args(&mut self, _: &TsExprWithTypeArgs) {}
+
+  fn visit_ts_interface_decl(&mut self, _: &TsInterfaceDecl) {}
+
+  fn visit_ts_type_alias_decl(&mut self, _: &TsTypeAliasDecl) {}
 }
\ No newline at end of file
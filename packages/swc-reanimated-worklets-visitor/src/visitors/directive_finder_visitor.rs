@@ -42,7 +42,7 @@ impl<C: Clone + swc_common::comments::Comments> VisitMut for DirectiveFinderVisi
       // TODO: There's no directive visitor
       if let Stmt::Expr(ExprStmt { expr, .. }) = stmt {
           if let Expr::Lit(Lit::Str(Str { value, .. })) = &**expr {
-              if &*value == "worklet" {
+              if value == "worklet" {
                   self.has_worklet_directive = true;
                   // remove 'worklet'; directive before calling .toString()
                   *stmt = Stmt::dummy();
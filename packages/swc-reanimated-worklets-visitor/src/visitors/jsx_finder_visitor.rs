@@ -0,0 +1,33 @@
+use swc_common::Span;
+use swc_ecmascript::{
+    ast::*,
+    visit::{Visit, VisitWith},
+};
+
+/// Finds the first JSX element or fragment in the visited node.
+#[derive(Default)]
+pub struct JsxFinderVisitor {
+    pub jsx_span: Option<Span>,
+}
+
+impl JsxFinderVisitor {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl Visit for JsxFinderVisitor {
+    fn visit_jsx_element(&mut self, element: &JSXElement) {
+        self.jsx_span.get_or_insert(element.span);
+    }
+
+    fn visit_jsx_fragment(&mut self, fragment: &JSXFragment) {
+        self.jsx_span.get_or_insert(fragment.span);
+    }
+
+    fn visit_expr(&mut self, expr: &Expr) {
+        if self.jsx_span.is_none() {
+            expr.visit_children_with(self);
+        }
+    }
+}
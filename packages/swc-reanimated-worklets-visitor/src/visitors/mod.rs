@@ -2,7 +2,13 @@ mod optimization_finder_visitor;
 pub use optimization_finder_visitor::OptimizationFinderVisitor;
 mod directive_finder_visitor;
 pub use directive_finder_visitor::DirectiveFinderVisitor;
+mod jsx_finder_visitor;
+pub use jsx_finder_visitor::JsxFinderVisitor;
+mod worklet_string_validator;
+pub use worklet_string_validator::WorkletStringValidator;
 mod closure_ident_visitor;
 pub use closure_ident_visitor::ClosureIdentVisitor;
 mod reanimated_workles_visitor;
-pub use reanimated_workles_visitor::{ReanimatedWorkletsVisitor, WorkletsOptions};
+pub use reanimated_workles_visitor::{
+    JsxInWorklets, ReanimatedWorkletsVisitor, WorkletFormat, WorkletsOptions,
+};
@@ -1,51 +1,99 @@
 use swc_ecmascript::{
   ast::*,
-  visit::Visit,
+  visit::{Visit, VisitWith},
 };
 
-use crate::{constants::{STATEMENTLESS_FLAG, FUNCTIONLESS_FLAG, POSSIBLE_OPT_FUNCTION}, utils::get_callee_expr_ident};
+use crate::constants::{STATEMENTLESS_FLAG, FUNCTIONLESS_FLAG};
 
-pub struct OptimizationFinderVisitor {
+/// Finds statements and function calls in a worklet to calculate `__optimalization` flags.
+/// The runtime relies on these flags to skip work, so anything not known to be safe
+/// is treated as a statement / function call.
+pub struct OptimizationFinderVisitor<'a> {
   is_stmt: bool,
   is_fn_call: bool,
+  pure_functions: &'a [String],
 }
 
-impl OptimizationFinderVisitor {
-  pub fn new() -> Self {
+impl<'a> OptimizationFinderVisitor<'a> {
+  pub fn new(pure_functions: &'a [String]) -> Self {
       OptimizationFinderVisitor {
           is_stmt: false,
           is_fn_call: false,
+          pure_functions,
       }
   }
 
   pub fn calculate_flags(&self) -> i32 {
       let mut flags = 0;
       if !self.is_fn_call {
-          flags = flags | FUNCTIONLESS_FLAG;
+          flags |= FUNCTIONLESS_FLAG;
       }
 
       if !self.is_stmt {
-          flags = flags | STATEMENTLESS_FLAG;
+          flags |= STATEMENTLESS_FLAG;
       }
 
       flags
   }
+
+  /// Only plain calls to allow-listed functions, i.e. `interpolate(..)`, are not
+  /// counted as function calls. Member, computed, optional and other callees
+  /// can't be resolved statically.
+  fn is_pure_callee(&self, callee: &Callee) -> bool {
+      match callee {
+          Callee::Expr(expr) => match expr.unwrap_parens() {
+              Expr::Ident(ident) => self.pure_functions.iter().any(|v| *v == *ident.sym),
+              _ => false,
+          },
+          _ => false,
+      }
+  }
 }
 
-impl Visit for OptimizationFinderVisitor {
-  fn visit_if_stmt(&mut self, _if: &IfStmt) {
-      self.is_stmt = true;
+impl<'a> Visit for OptimizationFinderVisitor<'a> {
+  fn visit_stmt(&mut self, stmt: &Stmt) {
+      match stmt {
+          Stmt::If(..)
+          | Stmt::Switch(..)
+          | Stmt::For(..)
+          | Stmt::ForIn(..)
+          | Stmt::ForOf(..)
+          | Stmt::While(..)
+          | Stmt::DoWhile(..)
+          | Stmt::Try(..)
+          | Stmt::Labeled(..)
+          | Stmt::Break(..)
+          | Stmt::Continue(..)
+          | Stmt::Throw(..)
+          | Stmt::With(..) => {
+              self.is_stmt = true;
+          }
+          _ => {}
+      }
+
+      stmt.visit_children_with(self);
   }
 
   fn visit_call_expr(&mut self, call_expr: &CallExpr) {
-      if let Callee::Expr(expr) = &call_expr.callee {
-          let name = get_callee_expr_ident(&*expr);
-
-          if let Some(name) = name {
-              if !POSSIBLE_OPT_FUNCTION.iter().any(|v| *v == &*name.sym) {
-                  self.is_fn_call = true;
-              }
-          }
+      if !self.is_pure_callee(&call_expr.callee) {
+          self.is_fn_call = true;
       }
+
+      call_expr.visit_children_with(self);
+  }
+
+  fn visit_opt_call(&mut self, opt_call: &OptCall) {
+      self.is_fn_call = true;
+      opt_call.visit_children_with(self);
+  }
+
+  fn visit_new_expr(&mut self, new_expr: &NewExpr) {
+      self.is_fn_call = true;
+      new_expr.visit_children_with(self);
+  }
+
+  fn visit_tagged_tpl(&mut self, tagged_tpl: &TaggedTpl) {
+      self.is_fn_call = true;
+      tagged_tpl.visit_children_with(self);
   }
-}
\ No newline at end of file
+}
@@ -1,30 +1,85 @@
-use std::path::PathBuf;
-use swc_common::Mark;
+use serde::Deserialize;
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+    str::FromStr,
+};
 
-use crate::{constants::{GESTURE_HANDLER_GESTURE_OBJECTS, OBJECT_HOOKS, FUNCTION_ARGS_TO_WORKLETIZE, GESTURE_HANDLER_BUILDER_METHODS}, utils::{Scope, ScopeKind, get_callee_expr_ident}, calculate_hash};
-use swc_common::{util::take::Take, FileName, Span, DUMMY_SP};
+use crate::{constants::{OBJECT_HOOKS, FUNCTION_ARGS_TO_WORKLETIZE, GESTURE_HANDLER_BUILDER_METHODS, GESTURE_HANDLER_GESTURE_OBJECTS, GESTURE_HANDLER_MODULE, GLOBALS, POSSIBLE_OPT_FUNCTION, HOOK_OPTIMIZATION_FLAGS, WORKLET_FACTORY_FUNCTIONS, LAYOUT_ANIMATION_CALLBACK_METHODS, LAYOUT_ANIMATION_OBJECTS, REANIMATED_MODULE}, utils::{Scope, get_callee_expr_ident, has_worklet_directive, CompatAssumptions, CompatConfig, CompatPass, Diagnostics, InlineHelpers, minify_worklet, ReadOnlyComments, ScriptPass, Rule, Severity, WorkletsTarget}, calculate_hash};
+use swc_common::{comments::NoopComments, sync::Lrc, util::take::Take, FileName, Mark, SourceMap, Span, Spanned, DUMMY_SP};
+use swc_ecma_transforms_base::{fixer::fixer, helpers::{Helpers, HELPERS}, hygiene::hygiene, resolver};
 use swc_ecma_codegen::{self, text_writer::WriteJs, Emitter, Node};
-use swc_ecma_transforms_compat::{
-    es2015::{arrow, shorthand, template_literal},
-    es2020::{nullish_coalescing, optional_chaining},
-};
 use swc_ecmascript::{
     ast::*,
-    visit::{VisitMut, VisitMutWith, VisitWith},
+    transforms::{react::{jsx, Options as JsxOptions}, typescript::strip},
+    utils::{find_pat_ids, IdentUsageFinder},
+    visit::{Visit, VisitMut, VisitMutWith, VisitWith},
 };
 
-use super::{OptimizationFinderVisitor, ClosureIdentVisitor, DirectiveFinderVisitor};
+use crate::report::{WorkletDetection, WorkletReport, WorkletsReportCollector};
+
+use super::{OptimizationFinderVisitor, ClosureIdentVisitor, DirectiveFinderVisitor, JsxFinderVisitor, WorkletStringValidator};
 
 pub struct ReanimatedWorkletsVisitor<
     C: Clone + swc_common::comments::Comments,
     S: swc_common::SourceMapper + SourceMapperExt,
 > {
     globals: Vec<String>,
+    gesture_objects: Vec<String>,
+    gesture_builder_methods: Vec<String>,
+    pure_functions: Vec<String>,
+    check_worklet_calls: bool,
+    /// Inject runtime warnings for `.value` reads in inline styles.
+    inline_styles_warning: bool,
+    /// Emit worklets without the data only the native runtime needs, i.e. for web builds.
+    omit_native_only_data: bool,
+    /// Emit lightweight worklets for Reanimated's Jest mocks.
+    test_mode: bool,
+    jsx_in_worklets: JsxInWorklets,
+    /// Compat passes run over worklets before they're stringified.
+    compat: CompatConfig,
+    validate_worklets: bool,
+    mangle_worklets: bool,
+    worklet_format: WorkletFormat,
+    diagnostics: Diagnostics,
     filename: FileName,
-    in_use_animated_style: bool,
+    /// Set of `__optimalization` flags the hook currently being processed opts into.
+    optimization_flags: Option<i32>,
     source_map: std::sync::Arc<S>,
     relative_cwd: Option<PathBuf>,
     in_gesture_handler_event_callback: bool,
+    /// Local bindings initialized with a gesture object,
+    /// i.e. `const pan = Gesture.Pan();`
+    gesture_bindings: HashSet<Id>,
+    /// Subset of `gesture_bindings` configured with `.runOnJS(true)`.
+    run_on_js_gesture_bindings: HashSet<Id>,
+    /// Set while visiting the callee of a gesture builder chain containing `.runOnJS(true)`.
+    in_run_on_js_gesture_chain: bool,
+    /// Local names of `Gesture` imported from react-native-gesture-handler,
+    /// i.e. `import { Gesture as G } from 'react-native-gesture-handler';`
+    gesture_imports: HashSet<Id>,
+    /// Namespace imports of react-native-gesture-handler,
+    /// i.e. `import * as RNGH from 'react-native-gesture-handler';`
+    gesture_handler_namespace_imports: HashSet<Id>,
+    /// Local names of layout animations imported from react-native-reanimated,
+    /// i.e. `import { FadeIn as Fade } from 'react-native-reanimated';`
+    layout_animation_imports: HashSet<Id>,
+    /// Default and namespace imports of react-native-reanimated,
+    /// i.e. `import Animated from 'react-native-reanimated';`
+    reanimated_namespace_imports: HashSet<Id>,
+    /// Local names imported from any other module.
+    foreign_imports: HashSet<Id>,
+    /// Identifiers passed where a worklet is expected, i.e. `useAnimatedStyle(styleFn)`,
+    /// with the optimization flags of the hook they're passed to.
+    /// Resolved to same-module function declarations once the whole module is visited.
+    worklet_references: Vec<(Ident, Option<i32>)>,
+    /// Local bindings of functions already workletized,
+    /// i.e. `function foo() { 'worklet'; }`
+    worklet_bindings: HashSet<Id>,
+    /// Functions called from worklets which are declared outside of them,
+    /// checked once the module is visited if `check_worklet_calls` is enabled.
+    worklet_callees: Vec<Ident>,
+    report: Option<WorkletsReportCollector>,
     comments: C,
 }
 
@@ -32,44 +87,134 @@ impl<C: Clone + swc_common::comments::Comments, S: swc_common::SourceMapper + So
     ReanimatedWorkletsVisitor<C, S>
 {
     pub fn new(
+        worklets_options: WorkletsOptions,
         source_map: std::sync::Arc<S>,
-        globals: Vec<String>,
-        filename: FileName,
-        relative_cwd: Option<PathBuf>,
         comments: C,
     ) -> Self {
+        let mut globals = GLOBALS.map(|v| v.to_string()).to_vec();
+
+        // allows adding custom globals such as host-functions
+        if let Some(custom_globals) = worklets_options.custom_globals {
+            globals.extend(custom_globals);
+        };
+
+        let mut gesture_objects = GESTURE_HANDLER_GESTURE_OBJECTS
+            .map(|v| v.to_string())
+            .to_vec();
+
+        // allows recognizing gestures not known to the plugin yet, such as newer
+        // react-native-gesture-handler gestures
+        if let Some(custom_gesture_objects) = worklets_options.custom_gesture_objects {
+            gesture_objects.extend(custom_gesture_objects);
+        };
+
+        let mut gesture_builder_methods = GESTURE_HANDLER_BUILDER_METHODS
+            .map(|v| v.to_string())
+            .to_vec();
+
+        // allows workletizing callbacks of builder methods not known to the plugin yet
+        if let Some(custom_gesture_builder_methods) =
+            worklets_options.custom_gesture_builder_methods
+        {
+            gesture_builder_methods.extend(custom_gesture_builder_methods);
+        };
+
+        let mut pure_functions = POSSIBLE_OPT_FUNCTION.map(|v| v.to_string()).to_vec();
+
+        // allows keeping optimization flags for worklets calling known side-effect free
+        // helpers, such as custom math functions
+        if let Some(custom_pure_functions) = worklets_options.custom_pure_functions {
+            pure_functions.extend(custom_pure_functions);
+        };
+
         ReanimatedWorkletsVisitor {
             source_map,
             globals,
-            filename,
-            relative_cwd,
-            in_use_animated_style: false,
+            gesture_objects,
+            gesture_builder_methods,
+            pure_functions,
+            check_worklet_calls: worklets_options.check_worklet_calls,
+            // Runtime warnings are only injected in development builds.
+            inline_styles_warning: worklets_options.dev
+                && !worklets_options.disable_inline_styles_warning,
+            omit_native_only_data: worklets_options.omit_native_only_data,
+            test_mode: worklets_options.test_mode,
+            jsx_in_worklets: worklets_options.jsx_in_worklets,
+            compat: CompatConfig {
+                target: worklets_options.target,
+                passes: worklets_options.compat_passes,
+                assumptions: worklets_options.assumptions,
+            },
+            validate_worklets: worklets_options.validate_worklets,
+            mangle_worklets: worklets_options.mangle_worklets,
+            worklet_format: worklets_options.worklet_format,
+            diagnostics: Diagnostics::new(
+                worklets_options.warnings_as_errors,
+                worklets_options.rules.unwrap_or_default(),
+            ),
+            filename: worklets_options.filename,
+            relative_cwd: worklets_options.relative_cwd,
+            optimization_flags: None,
             in_gesture_handler_event_callback: false,
+            gesture_bindings: Default::default(),
+            run_on_js_gesture_bindings: Default::default(),
+            in_run_on_js_gesture_chain: false,
+            gesture_imports: Default::default(),
+            gesture_handler_namespace_imports: Default::default(),
+            layout_animation_imports: Default::default(),
+            reanimated_namespace_imports: Default::default(),
+            foreign_imports: Default::default(),
+            worklet_references: Default::default(),
+            worklet_bindings: Default::default(),
+            worklet_callees: Default::default(),
+            report: None,
             comments,
         }
     }
 
+    /// Records a report of every generated worklet into the given collector.
+    pub fn with_report(mut self, report: WorkletsReportCollector) -> Self {
+        self.report = Some(report);
+        self
+    }
+
     /// Print givne fn's string with writer.
     /// This should be called with `cloned` node, as internally this'll take ownership.
-    fn build_worklet_string(&mut self, fn_name: Ident, expr: Expr, closure_ident: Ident) -> String {
+    /// Emits an error and returns `None` if the string can't be generated.
+    /// Returns the string along with its hash.
+    fn build_worklet_string(
+        &mut self,
+        fn_name: Ident,
+        expr: Expr,
+        _closure_ident: Ident,
+        span: &Span,
+    ) -> Option<(String, f64)> {
         let (params, body) = match expr {
             Expr::Arrow(mut arrow_expr) => (
                 arrow_expr.params.drain(..).map(Param::from).collect(),
                 arrow_expr.body,
             ),
-            Expr::Fn(fn_expr) => (
-                fn_expr.function.params,
-                BlockStmtOrExpr::BlockStmt(
-                    fn_expr
-                        .function
-                        .body
-                        .expect("Expect fn body exists to make worklet fn"),
-                ),
-            ),
-            _ => todo!("unexpected"),
+            Expr::Fn(FnExpr {
+                function:
+                    Function {
+                        params,
+                        body: Some(body),
+                        ..
+                    },
+                ..
+            }) => (params, BlockStmtOrExpr::BlockStmt(body)),
+            _ => {
+                self.diagnostics.emit(
+                    Rule::WorkletGeneration,
+                    *span,
+                    "Unable to generate a worklet from this node, only functions with a body \
+                     can be worklets",
+                );
+                return None;
+            }
         };
 
-        let mut body = match body {
+        let body = match body {
             BlockStmtOrExpr::BlockStmt(body) => body,
             BlockStmtOrExpr::Expr(e) => BlockStmt {
                 stmts: vec![Stmt::Expr(ExprStmt {
@@ -125,142 +270,320 @@ impl<C: Clone + swc_common::comments::Comments, S: swc_common::SourceMapper + So
         };
          */
 
-        let transformed_function = FnExpr {
+        let mut transformed_function = FnExpr {
             ident: Some(fn_name),
             function: Function {
                 params,
                 body: Some(body),
                 ..Function::dummy()
             },
-            ..FnExpr::dummy()
         };
 
+        if self.mangle_worklets {
+            minify_worklet(&mut transformed_function);
+        }
+
+        let func_string = self.print_worklet(&transformed_function, self.worklet_format, span)?;
+        // The hash identifies the worklet regardless of the format it's printed with,
+        // so it's always calculated from the minified string.
+        let func_hash = if self.worklet_format == WorkletFormat::Minified {
+            calculate_hash(&func_string)
+        } else {
+            calculate_hash(&self.print_worklet(&transformed_function, WorkletFormat::Minified, span)?)
+        };
+
+        Some((func_string, func_hash))
+    }
+
+    fn print_worklet(
+        &mut self,
+        function: &FnExpr,
+        format: WorkletFormat,
+        span: &Span,
+    ) -> Option<String> {
+        let comments = ReadOnlyComments(&self.comments);
+
         let mut buf = vec![];
         {
             let wr = Box::new(swc_ecma_codegen::text_writer::JsWriter::new(
                 Default::default(),
-                "", //"\n",
+                if format == WorkletFormat::Minified { "" } else { "\n" },
                 &mut buf,
                 None,
             )) as Box<dyn WriteJs>;
 
             let mut emitter = Emitter {
                 cfg: swc_ecma_codegen::Config {
-                    minify: true,
+                    minify: format == WorkletFormat::Minified,
                     ..Default::default()
                 },
-                comments: Default::default(),
+                comments: if format == WorkletFormat::PrettyWithComments {
+                    Some(&comments)
+                } else {
+                    None
+                },
                 cm: self.source_map.clone(),
                 wr,
             };
 
-            transformed_function
-                .emit_with(&mut emitter)
-                .ok()
-                .expect("Should emit");
+            if let Err(err) = function.emit_with(&mut emitter) {
+                self.diagnostics.emit(
+                    Rule::WorkletGeneration,
+                    *span,
+                    &format!("Unable to print the worklet: {}", err),
+                );
+                return None;
+            }
+        }
+
+        match String::from_utf8(buf) {
+            Ok(func_string) => Some(func_string),
+            Err(..) => {
+                self.diagnostics.emit(
+                    Rule::WorkletGeneration,
+                    *span,
+                    "Unable to print the worklet: invalid UTF-8 in output",
+                );
+                None
+            }
         }
-        String::from_utf8(buf).expect("invalid utf8 character detected")
     }
 
-    /// Actual fn to generate AST for worklet-ized function to be called across
-    /// fn-like nodes (arrow fn, fnExpr)
-    fn make_worklet_inner(
+    /// Runs the analysis and stringification of a worklet on a clone of the original
+    /// node. Returns `None` after emitting a diagnostic if the worklet can't be generated,
+    /// in which case the original node should be left untouched.
+    fn make_worklet_metadata(
         &mut self,
-        worklet_name: Option<Ident>,
+        worklet_name: &Option<Ident>,
         mut cloned: Expr,
         span: &Span,
-        mut body: BlockStmtOrExpr,
-        params: Vec<Param>,
-        is_generator: bool,
-        is_async: bool,
-        type_params: Option<TsTypeParamDecl>,
-        return_type: Option<TsTypeAnn>,
-        decorators: Option<Vec<Decorator>>,
-    ) -> Function {
-        let function_name = if let Some(ident) = &worklet_name {
-            ident.clone()
-        } else {
-            Ident::new("_f".into(), DUMMY_SP)
+        detection: WorkletDetection,
+    ) -> Option<WorkletMetadata> {
+        // The UI runtime has no event loop to resume async functions or generators on.
+        let (is_async, is_generator) = match &cloned {
+            Expr::Arrow(arrow_expr) => (arrow_expr.is_async, arrow_expr.is_generator),
+            Expr::Fn(fn_expr) => (fn_expr.function.is_async, fn_expr.function.is_generator),
+            _ => (false, false),
         };
-        let private_fn_name = Ident::new("_f".into(), DUMMY_SP);
+        if is_async || is_generator {
+            self.diagnostics.emit(
+                Rule::UnsupportedWorklet,
+                *span,
+                &format!(
+                    "{} functions can't be worklets, as they can't be run on the UI thread. \
+                     Use a regular function instead",
+                    if is_async { "Async" } else { "Generator" }
+                ),
+            );
+            return None;
+        }
 
-        let opt_flags = if self.in_use_animated_style {
-            let mut opt_find_visitor = OptimizationFinderVisitor::new();
-            cloned.visit_with(&mut opt_find_visitor);
+        // Web builds and tests run worklets as plain functions, so neither needs
+        // the worklet to be runnable on the UI thread.
+        let runs_on_ui_thread = !self.omit_native_only_data && !self.test_mode;
 
-            Some(opt_find_visitor.calculate_flags())
-        } else {
-            None
-        };
+        // JSX left in the worklet is compiled by the preprocessors otherwise,
+        // which is also the case when the rule reporting it is turned off.
+        if runs_on_ui_thread
+            && self.jsx_in_worklets == JsxInWorklets::Error
+            && self.diagnostics.severity(Rule::JsxInWorklet) != Severity::Off
+        {
+            let mut jsx_finder = JsxFinderVisitor::new();
+            cloned.visit_with(&mut jsx_finder);
+
+            if let Some(jsx_span) = jsx_finder.jsx_span {
+                self.diagnostics.emit(
+                    Rule::JsxInWorklet,
+                    jsx_span,
+                    "JSX can't be used in worklets, as it can't be run on the UI thread. \
+                     Move it out of the worklet, or set `jsxInWorklets` to `transform` \
+                     to compile it to `React.createElement` calls",
+                );
+                return None;
+            }
+        }
 
-        // TODO: this mimics existing plugin behavior runs specific transform pass
-        // before running actual visitor.
-        // 1. This may not required
-        // 2. If required, need to way to pass config to visitors instead of Default::default()
-        // https://github.com/software-mansion/react-native-reanimated/blob/b4ee4ea9a1f246c461dd1819c6f3d48440a25756/plugin.js#L367-L371=
-        let mut preprocessors: Vec<Box<dyn VisitMut>> = vec![
-            Box::new(shorthand()),
-            Box::new(arrow()),
-            Box::new(optional_chaining(Default::default())),
-            Box::new(nullish_coalescing(Default::default())),
-            Box::new(template_literal(Default::default())),
-        ];
+        let opt_flags = self
+            .optimization_flags
+            .filter(|_| runs_on_ui_thread)
+            .map(|hook_flags| {
+                let mut opt_find_visitor = OptimizationFinderVisitor::new(&self.pure_functions);
+                cloned.visit_with(&mut opt_find_visitor);
 
-        for mut preprocessor in preprocessors.drain(..) {
-            cloned.visit_mut_with(&mut *preprocessor);
-        }
+                opt_find_visitor.calculate_flags() & hook_flags
+            });
 
+        // Captures are computed before the preprocessors run, so references they inject
+        // into the worklet string, i.e. to helpers, are reported by the validator.
         let mut closure_visitor = ClosureIdentVisitor::new(
-            Scope::new(ScopeKind::Fn, Mark::new(), None),
+            Scope::new(None),
             &self.globals,
-            &worklet_name,
+            worklet_name,
         );
         cloned.visit_children_with(&mut closure_visitor);
 
-        let closure_ident = Ident::new("_closure".into(), DUMMY_SP);
-        let as_string_ident = Ident::new("asString".into(), DUMMY_SP);
-        let worklet_hash_ident = Ident::new("__workletHash".into(), DUMMY_SP);
-        let location_ident = Ident::new("__location".into(), DUMMY_SP);
-        let optimalization_ident = Ident::new("__optimalization".into(), DUMMY_SP);
+        if self.check_worklet_calls {
+            self.worklet_callees
+                .append(&mut closure_visitor.captured_callees);
+        }
+        let captured_variables = closure_visitor
+            .captures
+            .iter()
+            .map(|ident| ident.sym.to_string())
+            .collect::<Vec<_>>();
+
+        // Mimics the Babel plugin, which runs specific transform passes over the worklet
+        // before it's stringified. Which passes run depends on the configured target.
+        // https://github.com/software-mansion/react-native-reanimated/blob/b4ee4ea9a1f246c461dd1819c6f3d48440a25756/plugin.js#L367-L371=
+        if runs_on_ui_thread {
+            let mut preprocessors: Vec<Box<dyn VisitMut>> = vec![
+                // Types are kept on the JS-side function, only the stringified one is stripped.
+                Box::new(ScriptPass(strip(Mark::new()))),
+                // Classic runtime, as the UI runtime can't import the automatic one's factories.
+                // Spread props are kept as object spread instead of the `_extends` helper.
+                Box::new(ScriptPass(jsx(
+                    Lrc::new(SourceMap::default()),
+                    None::<NoopComments>,
+                    JsxOptions {
+                        use_spread: Some(true),
+                        ..Default::default()
+                    },
+                    Mark::new(),
+                ))),
+            ];
+            preprocessors.extend(self.compat.make_passes());
+            // Temporaries created by the compat passes, i.e. `ref` for optional chaining,
+            // are renamed if they conflict with names used by the worklet.
+            preprocessors.push(Box::new(ScriptPass(hygiene())));
+            // Compat passes don't parenthesize the expressions they create.
+            preprocessors.push(Box::new(fixer(None)));
+            preprocessors.push(Box::new(InlineHelpers));
+
+            // Helpers used by the worklet are tracked apart from the module's,
+            // so they aren't injected into the module as well.
+            HELPERS.set(&Helpers::new(false), || {
+                for mut preprocessor in preprocessors.drain(..) {
+                    cloned.visit_mut_with(&mut *preprocessor);
+                }
+            });
+        }
+
+        // Test mode takes precedence, as Jest runs web builds as well.
+        let output = if self.test_mode {
+            let code_location = self.make_worklet_location(span);
+            WorkletOutput::Test {
+                func_hash: calculate_hash(&code_location),
+                code_location,
+            }
+        } else if self.omit_native_only_data {
+            WorkletOutput::Web
+        } else {
+            let function_name = if let Some(ident) = worklet_name {
+                ident.clone()
+            } else {
+                Ident::new("_f".into(), DUMMY_SP)
+            };
+
+            let closure_ident = Ident::new("_closure".into(), DUMMY_SP);
+            let (func_string, func_hash) =
+                self.build_worklet_string(function_name, cloned, closure_ident, span)?;
+
+            if self.validate_worklets {
+                let validator = WorkletStringValidator::new(&self.globals, self.compat.passes());
+                if let Err(reason) = validator.validate(&func_string, &captured_variables) {
+                    self.diagnostics.emit(
+                        Rule::InvalidWorklet,
+                        *span,
+                        &format!("Generated worklet string {}", reason),
+                    );
+                }
+            }
+
+            WorkletOutput::Native {
+                func_hash,
+                func_string,
+                code_location: self.make_worklet_location(span),
+            }
+        };
+
+        if let Some(report) = &self.report {
+            let loc = self.source_map.lookup_char_pos(span.lo);
+            report.add(WorkletReport {
+                name: worklet_name.as_ref().map(|ident| ident.sym.to_string()),
+                hash: output.func_hash(),
+                location: output.code_location().map(|code_location| code_location.to_string()),
+                line: loc.line,
+                column: loc.col_display,
+                detection,
+                captured_variables,
+                optimization_flags: opt_flags,
+            });
+        }
 
-        let func_string =
-            self.build_worklet_string(function_name.clone(), cloned, closure_ident.clone());
-        let func_hash = calculate_hash(&func_string);
+        Some(WorkletMetadata { output, opt_flags })
+    }
 
+    /// Resolves the `__location` of a worklet, relative to the configured cwd if possible.
+    fn make_worklet_location(&mut self, span: &Span) -> String {
         /*
             const closureGenerator = new ClosureGenerator();
         */
 
         // Naive approach to calcuate relative path from options.
-        // Note this relies on plugin config option (relative_cwd) to pass specific cwd.
+        // Note this relies on the host to pass a specific cwd, either through the
+        // transform context or the plugin config option (relative_cwd).
         // unlike original babel plugin, we can't calculate cwd inside of plugin.
-        // TODO: This is not sound relative path calcuation
-        let filename_str = if let Some(relative_cwd) = &self.relative_cwd {
-            self.filename
-                .to_string()
-                .strip_prefix(
-                    relative_cwd
-                        .as_os_str()
-                        .to_str()
-                        .expect("Should able to convert cwd to string"),
-                )
-                .expect("Should able to strip relative cwd")
-                .to_string()
-        } else {
-            self.filename.to_string()
+        let filename_str = match (&self.filename, &self.relative_cwd) {
+            (FileName::Real(path), Some(relative_cwd)) => match path.strip_prefix(relative_cwd) {
+                Ok(relative_path) => relative_path.display().to_string(),
+                Err(..) => {
+                    self.diagnostics.emit(
+                        Rule::WorkletLocation,
+                        *span,
+                        &format!(
+                            "Unable to resolve the worklet location relative to `{}`, \
+                             using the absolute path instead",
+                            relative_cwd.display()
+                        ),
+                    );
+                    self.filename.to_string()
+                }
+            },
+            _ => self.filename.to_string(),
         };
 
         let loc = self.source_map.lookup_char_pos(span.lo);
-        let code_location = format!("{} ({}:{})", filename_str, loc.line, loc.col_display);
+        format!("{} ({}:{})", filename_str, loc.line, loc.col_display)
+    }
+
+    /// Actual fn to generate AST for worklet-ized function to be called across
+    /// fn-like nodes (arrow fn, fnExpr)
+    #[allow(clippy::too_many_arguments)]
+    fn make_worklet_inner(
+        &mut self,
+        metadata: WorkletMetadata,
+        mut body: BlockStmtOrExpr,
+        params: Vec<Param>,
+        is_generator: bool,
+        is_async: bool,
+        type_params: Option<TsTypeParamDecl>,
+        return_type: Option<TsTypeAnn>,
+        decorators: Option<Vec<Decorator>>,
+    ) -> Function {
+        let WorkletMetadata { output, opt_flags } = metadata;
+        let private_fn_name = Ident::new("_f".into(), DUMMY_SP);
+
+        let closure_ident = Ident::new("_closure".into(), DUMMY_SP);
+        let as_string_ident = Ident::new("asString".into(), DUMMY_SP);
+        let worklet_hash_ident = Ident::new("__workletHash".into(), DUMMY_SP);
+        let location_ident = Ident::new("__location".into(), DUMMY_SP);
+        let web_worklet_ident = Ident::new("__worklet".into(), DUMMY_SP);
+        let optimalization_ident = Ident::new("__optimalization".into(), DUMMY_SP);
 
         // TODO: need to use closuregenerator
         let dummy_closure = Expr::Object(ObjectLit::dummy());
 
-        let decorators = if let Some(decorators) = decorators {
-            decorators
-        } else {
-            Default::default()
-        };
+        let decorators = decorators.unwrap_or_default();
 
         let func_expr = match body.take() {
             BlockStmtOrExpr::BlockStmt(body) => Expr::Fn(FnExpr {
@@ -338,55 +661,70 @@ impl<C: Clone + swc_common::comments::Comments, S: swc_common::SourceMapper + So
                     right: Box::new(dummy_closure.clone()),
                 })),
             }),
-            // _f.asString
-            Stmt::Expr(ExprStmt {
-                span: DUMMY_SP,
-                expr: Box::new(Expr::Assign(AssignExpr {
-                    span: DUMMY_SP,
-                    op: AssignOp::Assign,
-                    left: PatOrExpr::Expr(Box::new(Expr::Member(MemberExpr {
-                        span: DUMMY_SP,
-                        obj: Box::new(Expr::Ident(private_fn_name.clone())),
-                        prop: MemberProp::Ident(as_string_ident.clone()),
-                    }))),
-                    // TODO: this is not complete
-                    right: Box::new(Expr::Lit(Lit::Str(Str::from(func_string)))),
-                })),
-            }),
-            //_f.__workletHash
-            Stmt::Expr(ExprStmt {
-                span: DUMMY_SP,
-                expr: Box::new(Expr::Assign(AssignExpr {
-                    span: DUMMY_SP,
-                    op: AssignOp::Assign,
-                    left: PatOrExpr::Expr(Box::new(Expr::Member(MemberExpr {
+        ];
+
+        match output {
+            WorkletOutput::Native {
+                func_string,
+                func_hash,
+                code_location,
+            } => stmts.extend([
+                // _f.asString
+                make_fn_prop_assign_stmt(
+                    &private_fn_name,
+                    as_string_ident.clone(),
+                    Expr::Lit(Lit::Str(Str::from(func_string))),
+                ),
+                // _f.__workletHash
+                make_fn_prop_assign_stmt(
+                    &private_fn_name,
+                    worklet_hash_ident.clone(),
+                    Expr::Lit(Lit::Num(Number {
                         span: DUMMY_SP,
-                        obj: Box::new(Expr::Ident(private_fn_name.clone())),
-                        prop: MemberProp::Ident(worklet_hash_ident.clone()),
-                    }))),
-                    // TODO: this is not complete
-                    right: Box::new(Expr::Lit(Lit::Num(Number {
+                        value: func_hash,
+                        raw: None,
+                    })),
+                ),
+                // _f.__location
+                make_fn_prop_assign_stmt(
+                    &private_fn_name,
+                    location_ident.clone(),
+                    Expr::Lit(Lit::Str(Str::from(code_location))),
+                ),
+            ]),
+            // Reanimated's mocks recognize worklets by their hash, which doesn't need
+            // to identify the worklet's code in tests.
+            WorkletOutput::Test {
+                func_hash,
+                code_location,
+            } => stmts.extend([
+                // _f.__workletHash
+                make_fn_prop_assign_stmt(
+                    &private_fn_name,
+                    worklet_hash_ident.clone(),
+                    Expr::Lit(Lit::Num(Number {
                         span: DUMMY_SP,
-                        value: func_hash.into(),
+                        value: func_hash,
                         raw: None,
-                    }))),
-                })),
-            }),
-            //_f.__location
-            Stmt::Expr(ExprStmt {
-                span: DUMMY_SP,
-                expr: Box::new(Expr::Assign(AssignExpr {
+                    })),
+                ),
+                // _f.__location
+                make_fn_prop_assign_stmt(
+                    &private_fn_name,
+                    location_ident.clone(),
+                    Expr::Lit(Lit::Str(Str::from(code_location))),
+                ),
+            ]),
+            // _f.__worklet = true, lets web runtimes recognize worklets without the native-only data
+            WorkletOutput::Web => stmts.push(make_fn_prop_assign_stmt(
+                &private_fn_name,
+                web_worklet_ident,
+                Expr::Lit(Lit::Bool(Bool {
                     span: DUMMY_SP,
-                    op: AssignOp::Assign,
-                    left: PatOrExpr::Expr(Box::new(Expr::Member(MemberExpr {
-                        span: DUMMY_SP,
-                        obj: Box::new(Expr::Ident(private_fn_name.clone())),
-                        prop: MemberProp::Ident(location_ident.clone()),
-                    }))),
-                    right: Box::new(Expr::Lit(Lit::Str(Str::from(code_location)))),
+                    value: true,
                 })),
-            }),
-        ];
+            )),
+        }
 
         if let Some(opt_flags) = opt_flags {
             stmts.push(Stmt::Expr(ExprStmt {
@@ -428,39 +766,54 @@ impl<C: Clone + swc_common::comments::Comments, S: swc_common::SourceMapper + So
         &mut self,
         ident: &mut Option<Ident>,
         function: &mut Function,
-    ) -> Function {
-        self.make_worklet_inner(
-            ident.clone(),
+        detection: WorkletDetection,
+    ) -> Option<Function> {
+        let metadata = self.make_worklet_metadata(
+            ident,
             // Have to clone to run transform preprocessor without changing original codes
             Expr::Fn(FnExpr {
-                ident: ident.take(),
+                ident: ident.clone(),
                 function: function.clone(),
             }),
             &function.span,
-            BlockStmtOrExpr::BlockStmt(
-                function
-                    .body
-                    .take()
-                    .expect("Expect fn body exists to make worklet fn"),
-            ),
+            detection,
+        )?;
+        let body = function.body.take()?;
+
+        Some(self.make_worklet_inner(
+            metadata,
+            BlockStmtOrExpr::BlockStmt(body),
             function.params.take(),
             function.is_generator,
             function.is_async,
             function.type_params.take(),
             function.return_type.take(),
             Some(function.decorators.take()),
-        )
+        ))
     }
 
-    fn make_worklet_from_fn_expr(&mut self, fn_expr: &mut FnExpr) -> Function {
-        self.make_worklet_from_fn(&mut fn_expr.ident, &mut fn_expr.function)
+    fn make_worklet_from_fn_expr(
+        &mut self,
+        fn_expr: &mut FnExpr,
+        detection: WorkletDetection,
+    ) -> Option<Function> {
+        self.make_worklet_from_fn(&mut fn_expr.ident, &mut fn_expr.function, detection)
     }
 
-    fn make_worklet_from_arrow(&mut self, arrow_expr: &mut ArrowExpr) -> Function {
-        self.make_worklet_inner(
-            None,
+    fn make_worklet_from_arrow(
+        &mut self,
+        arrow_expr: &mut ArrowExpr,
+        detection: WorkletDetection,
+    ) -> Option<Function> {
+        let metadata = self.make_worklet_metadata(
+            &None,
             Expr::Arrow(arrow_expr.clone()),
             &arrow_expr.span,
+            detection,
+        )?;
+
+        Some(self.make_worklet_inner(
+            metadata,
             arrow_expr.body.take(),
             arrow_expr.params.drain(..).map(Param::from).collect(),
             arrow_expr.is_generator,
@@ -468,14 +821,14 @@ impl<C: Clone + swc_common::comments::Comments, S: swc_common::SourceMapper + So
             arrow_expr.type_params.take(),
             arrow_expr.return_type.take(),
             None,
-        )
+        ))
     }
 
     fn process_if_fn_decl_worklet_node(&mut self, decl: &mut Decl) {
         let mut visitor = DirectiveFinderVisitor::new(self.comments.clone());
         decl.visit_mut_children_with(&mut visitor);
         if visitor.has_worklet_directive {
-            self.process_worklet_fn_decl(decl);
+            self.process_worklet_fn_decl(decl, WorkletDetection::Directive);
         }
     }
 
@@ -484,11 +837,15 @@ impl<C: Clone + swc_common::comments::Comments, S: swc_common::SourceMapper + So
         let mut visitor = DirectiveFinderVisitor::new(self.comments.clone());
         fn_like_expr.visit_mut_children_with(&mut visitor);
         if visitor.has_worklet_directive {
-            self.process_worklet_function(fn_like_expr);
+            self.process_worklet_function(fn_like_expr, WorkletDetection::Directive);
         }
     }
 
-    fn process_worklet_object_method(&mut self, method_prop: &mut PropOrSpread) {
+    fn process_worklet_object_method(
+        &mut self,
+        method_prop: &mut PropOrSpread,
+        detection: WorkletDetection,
+    ) {
         let key = if let PropOrSpread::Prop(prop) = method_prop {
             match &**prop {
                 Prop::Method(MethodProp { key, .. }) => Some(key.clone()),
@@ -508,7 +865,7 @@ impl<C: Clone + swc_common::comments::Comments, S: swc_common::SourceMapper + So
                         None
                     };
 
-                    Some(self.make_worklet_from_fn(&mut fn_ident, function))
+                    self.make_worklet_from_fn(&mut fn_ident, function, detection)
                 } else {
                     None
                 }
@@ -528,10 +885,18 @@ impl<C: Clone + swc_common::comments::Comments, S: swc_common::SourceMapper + So
         }
     }
 
-    fn process_worklet_fn_decl(&mut self, decl: &mut Decl) {
+    fn process_worklet_fn_decl(&mut self, decl: &mut Decl, detection: WorkletDetection) {
         if let Decl::Fn(fn_decl) = decl {
-            let worklet_fn =
-                self.make_worklet_from_fn(&mut Some(fn_decl.ident.clone()), &mut fn_decl.function);
+            self.worklet_bindings.insert(fn_decl.ident.to_id());
+
+            let worklet_fn = match self.make_worklet_from_fn(
+                &mut Some(fn_decl.ident.clone()),
+                &mut fn_decl.function,
+                detection,
+            ) {
+                Some(worklet_fn) => worklet_fn,
+                None => return,
+            };
 
             let declarator = VarDeclarator {
                 name: Pat::Ident(BindingIdent::from(fn_decl.ident.take())),
@@ -554,10 +919,14 @@ impl<C: Clone + swc_common::comments::Comments, S: swc_common::SourceMapper + So
     }
 
     // TODO: consolidate with process_worklet_fn_decl
-    fn process_worklet_function(&mut self, fn_like_expr: &mut Expr) {
+    /// Functions passed by reference are detected as `WorkletDetection::Reference` instead.
+    fn process_worklet_function(&mut self, fn_like_expr: &mut Expr, detection: WorkletDetection) {
         match fn_like_expr {
             Expr::Arrow(arrow_expr) => {
-                let fn_expr = self.make_worklet_from_arrow(arrow_expr);
+                let fn_expr = match self.make_worklet_from_arrow(arrow_expr, detection) {
+                    Some(fn_expr) => fn_expr,
+                    None => return,
+                };
 
                 *fn_like_expr = Expr::Call(CallExpr {
                     callee: Callee::Expr(Box::new(Expr::Fn(FnExpr {
@@ -567,62 +936,173 @@ impl<C: Clone + swc_common::comments::Comments, S: swc_common::SourceMapper + So
                     ..CallExpr::dummy()
                 });
             }
-            Expr::Fn(fn_expr) => {
-                // TODO: do we need to care about if fn body is empty?
-                if fn_expr.function.body.is_some() {
-                    let fn_expr = self.make_worklet_from_fn_expr(fn_expr);
-                    *fn_like_expr = Expr::Call(CallExpr {
-                        callee: Callee::Expr(Box::new(Expr::Fn(FnExpr {
-                            ident: Default::default(),
-                            function: fn_expr,
-                        }))),
-                        ..CallExpr::dummy()
-                    });
-                }
+            // TODO: do we need to care about if fn body is empty?
+            Expr::Fn(fn_expr) if fn_expr.function.body.is_some() => {
+                let fn_expr = match self.make_worklet_from_fn_expr(fn_expr, detection) {
+                    Some(fn_expr) => fn_expr,
+                    None => return,
+                };
+                *fn_like_expr = Expr::Call(CallExpr {
+                    callee: Callee::Expr(Box::new(Expr::Fn(FnExpr {
+                        ident: Default::default(),
+                        function: fn_expr,
+                    }))),
+                    ..CallExpr::dummy()
+                });
+            }
+            // Function passed by reference, i.e. `useAnimatedStyle(styleFn)`.
+            // Its declaration may not be visited yet, workletize it once the module is visited.
+            Expr::Ident(ident) if &*ident.sym != "undefined" => {
+                self.worklet_references
+                    .push((ident.clone(), self.optimization_flags));
             }
             _ => {}
         }
     }
 
+    /// Workletizes same-module function declarations referenced where a worklet is expected.
+    /// Emits a warning for references that can't be resolved statically, as those
+    /// will crash at runtime when called on the UI thread.
+    fn process_worklet_references<N>(&mut self, node: &mut N)
+    where
+        N: for<'v> VisitMutWith<WorkletReferencesVisitor<'v, C, S>>,
+    {
+        if self.worklet_references.is_empty() {
+            return;
+        }
+
+        let references = std::mem::take(&mut self.worklet_references);
+        let pending = references
+            .iter()
+            .map(|(ident, optimization_flags)| (ident.to_id(), *optimization_flags))
+            .filter(|(id, _)| !self.worklet_bindings.contains(id))
+            .collect::<HashMap<_, _>>();
+
+        let mut references_visitor = WorkletReferencesVisitor {
+            parent: self,
+            pending,
+            resolved: Default::default(),
+            referenced_above_captures: Default::default(),
+        };
+        node.visit_mut_with(&mut references_visitor);
+        let resolved = references_visitor.resolved;
+        let referenced_above_captures = references_visitor.referenced_above_captures;
+
+        let mut reported = HashSet::new();
+        for (ident, _) in references {
+            let id = ident.to_id();
+            if resolved.contains(&id) || self.worklet_bindings.contains(&id) {
+                continue;
+            }
+
+            if !reported.insert(id.clone()) {
+                continue;
+            }
+
+            if referenced_above_captures.contains(&id) {
+                self.diagnostics.emit(
+                    Rule::UnresolvedWorkletReference,
+                    ident.span,
+                    &format!(
+                        "`{}` captures variables declared below this reference, it won't be \
+                         workletized. Declare them above the reference",
+                        ident.sym
+                    ),
+                );
+            } else {
+                self.diagnostics.emit(
+                    Rule::UnresolvedWorkletReference,
+                    ident.span,
+                    &format!(
+                        "Unable to resolve `{}` to a function declared in this file, it won't be \
+                         workletized. Declare it in the same file or add a 'worklet' directive \
+                         to it",
+                        ident.sym
+                    ),
+                );
+            }
+        }
+    }
+
+    /// Warns about worklets calling same-module functions which aren't worklets,
+    /// as those crash when called synchronously on the UI thread.
+    fn check_worklet_callees<N>(&mut self, node: &N)
+    where
+        N: VisitWith<LocalFunctionsCollector>,
+    {
+        if self.worklet_callees.is_empty() {
+            return;
+        }
+
+        let mut collector = LocalFunctionsCollector::default();
+        node.visit_with(&mut collector);
+
+        for callee in std::mem::take(&mut self.worklet_callees) {
+            let id = callee.to_id();
+            if collector.functions.contains(&id) && !self.worklet_bindings.contains(&id) {
+                self.diagnostics.emit(
+                    Rule::NonWorkletCall,
+                    callee.span,
+                    &format!(
+                        "`{0}` is not a worklet and can't be called synchronously from a worklet. \
+                         Add a 'worklet' directive to `{0}` or call it on the JS thread with \
+                         `runOnJS({0})(...)`",
+                        callee.sym
+                    ),
+                );
+            }
+        }
+    }
+
+    /// Layout animation builders run the callback passed to `.withCallback()`
+    /// on the UI thread once the animation finishes.
+    fn process_layout_animation_callback(&mut self, call_expr: &mut CallExpr) {
+        if let Some(arg) = call_expr.args.last_mut() {
+            self.process_worklet_function(&mut arg.expr, WorkletDetection::LayoutAnimation);
+        }
+    }
+
     fn process_worklets(&mut self, call_expr: &mut CallExpr) {
-        let old = self.in_use_animated_style;
+        let old = self.optimization_flags;
         let name = if let Callee::Expr(expr) = &call_expr.callee {
-            get_callee_expr_ident(&*expr)
+            get_callee_expr_ident(expr)
         } else {
             None
         };
 
-        match name {
-            Some(name) if OBJECT_HOOKS.contains(&&*name.sym) && call_expr.args.len() > 0 => {
-                if &*name.sym == "useAnimatedStyle" {
-                    self.in_use_animated_style = true;
-                }
-
-                let arg = call_expr.args.get_mut(0).expect("should have args");
+        if let Some(name) = &name {
+            self.optimization_flags = HOOK_OPTIMIZATION_FLAGS.get(&*name.sym).copied();
+        }
 
-                if let Expr::Object(object_expr) = &mut *arg.expr {
+        match name {
+            Some(name) if OBJECT_HOOKS.contains(&&*name.sym) && !call_expr.args.is_empty() => {
+                if let Some(Expr::Object(object_expr)) =
+                    call_expr.args.get_mut(0).map(|arg| &mut *arg.expr)
+                {
                     let properties = &mut object_expr.props;
                     for property in properties {
                         if let PropOrSpread::Prop(prop) = property {
                             match &mut **prop {
                                 Prop::Method(..) => {
-                                    self.process_worklet_object_method(property);
+                                    self.process_worklet_object_method(
+                                        property,
+                                        WorkletDetection::Hook,
+                                    );
                                 }
                                 Prop::KeyValue(KeyValueProp { value, .. }) => {
-                                    self.process_worklet_function(&mut **value);
+                                    self.process_worklet_function(value, WorkletDetection::Hook);
+                                }
+                                Prop::Shorthand(ident) => {
+                                    self.worklet_references
+                                        .push((ident.clone(), self.optimization_flags));
                                 }
                                 _ => {}
                             };
                         }
                     }
                 }
-                self.in_use_animated_style = false;
             }
             Some(name) => {
-                if &*name.sym == "useAnimatedStyle" {
-                    self.in_use_animated_style = true;
-                }
-
                 let indexes = FUNCTION_ARGS_TO_WORKLETIZE.get(&*name.sym);
 
                 if let Some(indexes) = indexes {
@@ -630,129 +1110,645 @@ impl<C: Clone + swc_common::comments::Comments, S: swc_common::SourceMapper + So
                         let arg = call_expr.args.get_mut(*idx);
 
                         if let Some(arg) = arg {
-                            self.process_worklet_function(&mut *arg.expr);
+                            self.process_worklet_function(&mut arg.expr, WorkletDetection::Hook);
                         }
                     });
                 }
-
-                self.in_use_animated_style = old;
             }
             _ => {}
         }
+
+        self.optimization_flags = old;
     }
-}
 
-/// Checks if node matches `Gesture.Tap()` or similar.
-/*
-node: CallExpression(
-callee: MemberExpression(
-    object: Identifier('Gesture')
-    property: Identifier('Tap')
-)
-)
-*/
-fn is_gesture_object(expr: &Expr) -> bool {
-    if let Expr::Call(call_expr) = expr {
-        if let Callee::Expr(callee) = &call_expr.callee {
-            if let Expr::Member(member_expr) = &**callee {
-                if let Expr::Ident(ident) = &*member_expr.obj {
-                    if let MemberProp::Ident(prop_ident) = &member_expr.prop {
-                        return &*ident.sym == "Gesture"
-                            && GESTURE_HANDLER_GESTURE_OBJECTS
-                                .iter()
-                                .any(|m| *m == &*prop_ident.sym);
+    /// Collects imports from react-native-gesture-handler and react-native-reanimated to
+    /// resolve the `Gesture` object and layout animations even if they're aliased or
+    /// accessed through a namespace import.
+    fn collect_imports(&mut self, module: &Module) {
+        for item in &module.body {
+            if let ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) = item {
+                let is_gesture_handler_module = &*import_decl.src.value == GESTURE_HANDLER_MODULE;
+                let is_reanimated_module = &*import_decl.src.value == REANIMATED_MODULE;
+
+                for specifier in &import_decl.specifiers {
+                    match specifier {
+                        ImportSpecifier::Named(ImportNamedSpecifier {
+                            local, imported, ..
+                        }) if is_gesture_handler_module || is_reanimated_module => {
+                            let imported_name = match imported {
+                                Some(ModuleExportName::Ident(ident)) => &*ident.sym,
+                                Some(ModuleExportName::Str(str)) => &*str.value,
+                                None => &*local.sym,
+                            };
+
+                            if is_gesture_handler_module && imported_name == "Gesture" {
+                                self.gesture_imports.insert(local.to_id());
+                            } else if is_reanimated_module
+                                && LAYOUT_ANIMATION_OBJECTS.contains(&imported_name)
+                            {
+                                self.layout_animation_imports.insert(local.to_id());
+                            } else {
+                                self.foreign_imports.insert(local.to_id());
+                            }
+                        }
+                        ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. })
+                            if is_gesture_handler_module =>
+                        {
+                            self.gesture_handler_namespace_imports
+                                .insert(local.to_id());
+                        }
+                        ImportSpecifier::Default(ImportDefaultSpecifier { local, .. })
+                        | ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. })
+                            if is_reanimated_module =>
+                        {
+                            self.reanimated_namespace_imports.insert(local.to_id());
+                        }
+                        ImportSpecifier::Named(ImportNamedSpecifier { local, .. })
+                        | ImportSpecifier::Default(ImportDefaultSpecifier { local, .. })
+                        | ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. }) => {
+                            self.foreign_imports.insert(local.to_id());
+                        }
                     }
                 }
             }
         }
     }
 
-    false
-}
-
-/// Checks if node matches the pattern `Gesture.Foo()[*]`
-/// where `[*]` represents any number of chained method calls, like `.something(42)`.
-fn contains_gesture_object(expr: &Expr) -> bool {
-    // direct call
-    if is_gesture_object(expr) {
-        return true;
+    /// Collects bindings configured with `.runOnJS(true)` anywhere in the program, i.e.
+    /// `pan.runOnJS(true);`, as that may follow the calls registering their callbacks.
+    fn collect_run_on_js_gesture_bindings<N>(&mut self, node: &N)
+    where
+        N: VisitWith<RunOnJsBindingsCollector>,
+    {
+        let mut collector = RunOnJsBindingsCollector::default();
+        node.visit_with(&mut collector);
+        for ident in collector.roots {
+            // `Gesture.Pan().runOnJS(true)` configures the created gesture only.
+            if !self.is_gesture_namespace(&Expr::Ident(ident.clone())) {
+                self.run_on_js_gesture_bindings.insert(ident.to_id());
+            }
+        }
     }
 
-    // method chaining
-    if let Expr::Call(call_expr) = expr {
-        if let Callee::Expr(expr) = &call_expr.callee {
-            if let Expr::Member(expr) = &**expr {
-                return contains_gesture_object(&expr.obj);
+    /// Checks if node refers to react-native-gesture-handler's `Gesture` object:
+    /// - `Gesture`, imported from react-native-gesture-handler or not imported at all
+    /// - `G`, when imported as `import { Gesture as G } from 'react-native-gesture-handler'`
+    /// - `RNGH.Gesture`, when imported as `import * as RNGH from 'react-native-gesture-handler'`
+    fn is_gesture_namespace(&self, expr: &Expr) -> bool {
+        match expr {
+            Expr::Ident(ident) => {
+                let id = ident.to_id();
+                self.gesture_imports.contains(&id)
+                    || (&*ident.sym == "Gesture" && !self.foreign_imports.contains(&id))
+            }
+            Expr::Member(MemberExpr {
+                obj,
+                prop: MemberProp::Ident(prop_ident),
+                ..
+            }) => {
+                if let Expr::Ident(obj_ident) = &**obj {
+                    &*prop_ident.sym == "Gesture"
+                        && self
+                            .gesture_handler_namespace_imports
+                            .contains(&obj_ident.to_id())
+                } else {
+                    false
+                }
             }
+            _ => false,
         }
     }
-    return false;
-}
 
-/// Checks if node matches the pattern `Gesture.Foo()[*].onBar`
-/// where `[*]` represents any number of method calls.
-fn is_gesture_object_event_callback_method(callee: &Callee) -> bool {
-    if let Callee::Expr(expr) = callee {
-        if let Expr::Member(expr) = &**expr {
-            if let MemberProp::Ident(ident) = &expr.prop {
-                if GESTURE_HANDLER_BUILDER_METHODS
-                    .iter()
-                    .any(|m| *m == &*ident.sym)
-                {
-                    return contains_gesture_object(&*expr.obj);
+    /// Checks if node matches `Gesture.Tap()` or similar.
+    /*
+    node: CallExpression(
+    callee: MemberExpression(
+        object: Identifier('Gesture')
+        property: Identifier('Tap')
+    )
+    )
+    */
+    fn is_gesture_object(&self, expr: &Expr) -> bool {
+        if let Expr::Call(call_expr) = expr {
+            if let Callee::Expr(callee) = &call_expr.callee {
+                if let Expr::Member(member_expr) = &**callee {
+                    if let MemberProp::Ident(prop_ident) = &member_expr.prop {
+                        return self.is_gesture_namespace(&member_expr.obj)
+                            && self.gesture_objects.iter().any(|m| m == &*prop_ident.sym);
+                    }
                 }
             }
         }
+
+        false
     }
 
-    return false;
-}
+    /// Checks if node matches the pattern `Gesture.Foo()[*]`
+    /// where `[*]` represents any number of chained method calls, like `.something(42)`.
+    /// The chain may also start from a binding initialized with a gesture object,
+    /// i.e. `const pan = Gesture.Pan(); pan.onStart(..)`.
+    fn contains_gesture_object(&self, expr: &Expr) -> bool {
+        // direct call
+        if self.is_gesture_object(expr) {
+            return true;
+        }
+
+        match expr {
+            // binding to a gesture object
+            Expr::Ident(ident) => self.gesture_bindings.contains(&ident.to_id()),
+            Expr::Paren(ParenExpr { expr, .. }) => self.contains_gesture_object(expr),
+            // method chaining
+            Expr::Call(CallExpr {
+                callee: Callee::Expr(callee),
+                ..
+            }) => {
+                if let Expr::Member(expr) = &**callee {
+                    self.contains_gesture_object(&expr.obj)
+                } else {
+                    false
+                }
+            }
+            _ => false,
+        }
+    }
+
+    /// Checks if node matches the pattern `Gesture.Foo()[*].onBar`
+    /// where `[*]` represents any number of method calls.
+    fn is_gesture_object_event_callback_method(&self, callee: &Callee) -> bool {
+        if let Callee::Expr(expr) = callee {
+            if let Expr::Member(expr) = &**expr {
+                if let MemberProp::Ident(ident) = &expr.prop {
+                    if self
+                        .gesture_builder_methods
+                        .iter()
+                        .any(|m| m == &*ident.sym)
+                    {
+                        return self.contains_gesture_object(&expr.obj);
+                    }
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Checks if node is a method call on a gesture object chain,
+    /// i.e. `Gesture.Foo()[*].bar()`.
+    fn is_gesture_object_chain_call(&self, call_expr: &CallExpr) -> bool {
+        if let Callee::Expr(callee) = &call_expr.callee {
+            if let Expr::Member(member_expr) = &**callee {
+                return self.contains_gesture_object(&member_expr.obj);
+            }
+        }
+
+        false
+    }
+
+    /// Checks if a gesture object chain contains `.runOnJS(true)`, or starts from
+    /// a binding configured with it.
+    fn gesture_object_runs_on_js(&self, expr: &Expr) -> bool {
+        match expr {
+            Expr::Ident(ident) => self.run_on_js_gesture_bindings.contains(&ident.to_id()),
+            Expr::Paren(ParenExpr { expr, .. }) => self.gesture_object_runs_on_js(expr),
+            Expr::Call(call_expr) => self.gesture_call_runs_on_js(call_expr),
+            _ => false,
+        }
+    }
+
+    fn gesture_call_runs_on_js(&self, call_expr: &CallExpr) -> bool {
+        if let Callee::Expr(callee) = &call_expr.callee {
+            if let Expr::Member(member_expr) = &**callee {
+                return is_run_on_js_modifier(call_expr)
+                    || self.gesture_object_runs_on_js(&member_expr.obj);
+            }
+        }
+
+        false
+    }
+
+    /// Returns gesture object chain a variable initializer evaluates to, either
+    /// directly (`Gesture.Pan()[*]`) or memoized (`useMemo(() => Gesture.Pan()[*], [])`).
+    fn find_gesture_object_initializer<'e>(&mut self, init: &'e Expr) -> Option<&'e Expr> {
+        if self.contains_gesture_object(init) {
+            return Some(init);
+        }
+
+        if let Expr::Call(CallExpr {
+            callee: Callee::Expr(callee),
+            args,
+            ..
+        }) = init
+        {
+            let is_use_memo = get_callee_expr_ident(callee)
+                .map(|ident| &*ident.sym == "useMemo")
+                .unwrap_or(false);
+
+            if is_use_memo {
+                if let Some(factory) = args.first() {
+                    return self.find_returned_gesture_object(&factory.expr);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Returns gesture object chain given fn-like node returns, if any. Bindings declared
+    /// in its body are resolved, i.e. `const tap = Gesture.Tap(); return tap;`.
+    fn find_returned_gesture_object<'e>(&mut self, fn_like_expr: &'e Expr) -> Option<&'e Expr> {
+        let body = match fn_like_expr {
+            Expr::Arrow(ArrowExpr {
+                body: BlockStmtOrExpr::Expr(expr),
+                ..
+            }) => {
+                return if self.contains_gesture_object(expr) {
+                    Some(&**expr)
+                } else {
+                    None
+                }
+            }
+            Expr::Arrow(ArrowExpr {
+                body: BlockStmtOrExpr::BlockStmt(body),
+                ..
+            }) => body,
+            Expr::Fn(FnExpr {
+                function: Function {
+                    body: Some(body), ..
+                },
+                ..
+            }) => body,
+            _ => return None,
+        };
+
+        for stmt in &body.stmts {
+            match stmt {
+                Stmt::Decl(Decl::Var(var_decl)) => {
+                    for declarator in &var_decl.decls {
+                        self.collect_gesture_binding(declarator);
+                    }
+                }
+                Stmt::Return(ReturnStmt { arg: Some(arg), .. })
+                    if self.contains_gesture_object(arg) =>
+                {
+                    return Some(arg);
+                }
+                _ => {}
+            }
+        }
+
+        None
+    }
+
+    /// Tracks a binding initialized with a gesture object chain, and whether it's
+    /// configured with `.runOnJS(true)`.
+    fn collect_gesture_binding(&mut self, declarator: &VarDeclarator) {
+        if let (Pat::Ident(BindingIdent { id, .. }), Some(init)) =
+            (&declarator.name, &declarator.init)
+        {
+            if let Some(gesture_object) = self.find_gesture_object_initializer(init) {
+                self.gesture_bindings.insert(id.to_id());
+                if self.gesture_object_runs_on_js(gesture_object) {
+                    self.run_on_js_gesture_bindings.insert(id.to_id());
+                }
+            }
+        }
+    }
+
+    /// Checks if node refers to a react-native-reanimated layout animation:
+    /// - `FadeIn`, imported from react-native-reanimated or not imported at all
+    /// - `Fade`, when imported as `import { FadeIn as Fade } from 'react-native-reanimated'`
+    /// - `Animated.FadeIn`, when `Animated` is the default or namespace import
+    /// - `new Keyframe(...)`, for any of the above
+    fn is_layout_animation_object(&self, expr: &Expr) -> bool {
+        match expr {
+            Expr::Ident(ident) => {
+                let id = ident.to_id();
+                self.layout_animation_imports.contains(&id)
+                    || (LAYOUT_ANIMATION_OBJECTS.contains(&&*ident.sym)
+                        && !self.foreign_imports.contains(&id))
+            }
+            Expr::Member(MemberExpr {
+                obj,
+                prop: MemberProp::Ident(prop_ident),
+                ..
+            }) => {
+                if let Expr::Ident(obj_ident) = &**obj {
+                    LAYOUT_ANIMATION_OBJECTS.contains(&&*prop_ident.sym)
+                        && self
+                            .reanimated_namespace_imports
+                            .contains(&obj_ident.to_id())
+                } else {
+                    false
+                }
+            }
+            Expr::New(NewExpr { callee, .. }) => self.is_layout_animation_object(callee),
+            _ => false,
+        }
+    }
+
+    /// Checks if node matches the pattern `FadeIn[*]`
+    /// where `[*]` represents any number of chained method calls, like `.duration(300)`.
+    fn contains_layout_animation_object(&self, expr: &Expr) -> bool {
+        // direct reference
+        if self.is_layout_animation_object(expr) {
+            return true;
+        }
+
+        // method chaining
+        if let Expr::Call(call_expr) = expr {
+            if let Callee::Expr(expr) = &call_expr.callee {
+                if let Expr::Member(expr) = &**expr {
+                    return self.contains_layout_animation_object(&expr.obj);
+                }
+            }
+        }
+        false
+    }
+
+    /// Checks if node matches the pattern `FadeIn[*].withCallback`
+    /// where `[*]` represents any number of method calls.
+    fn is_layout_animation_callback_method(&self, callee: &Callee) -> bool {
+        if let Callee::Expr(expr) = callee {
+            if let Expr::Member(expr) = &**expr {
+                if let MemberProp::Ident(ident) = &expr.prop {
+                    if LAYOUT_ANIMATION_CALLBACK_METHODS
+                        .iter()
+                        .any(|m| *m == &*ident.sym)
+                    {
+                        return self.contains_layout_animation_object(&expr.obj);
+                    }
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Visits a method call on a gesture object chain. Callbacks passed to builder
+    /// methods are workletized, unless the chain is configured with `.runOnJS(true)`:
+    /// react-native-gesture-handler runs those on the JS thread instead.
+    fn process_gesture_object_chain_call(&mut self, call_expr: &mut CallExpr) {
+        let runs_on_js = self.in_run_on_js_gesture_chain || self.gesture_call_runs_on_js(call_expr);
+        let is_event_callback = self.is_gesture_object_event_callback_method(&call_expr.callee);
+
+        let old_run_on_js = self.in_run_on_js_gesture_chain;
+        let old_callback = self.in_gesture_handler_event_callback;
+
+        // rest of the chain configures the same gesture object
+        self.in_run_on_js_gesture_chain = runs_on_js;
+        call_expr.callee.visit_mut_with(self);
+        self.in_run_on_js_gesture_chain = false;
+
+        if is_event_callback {
+            if runs_on_js {
+                for arg in &call_expr.args {
+                    if has_worklet_directive(&arg.expr) {
+                        self.diagnostics.emit(
+                            Rule::WorkletOnJsThread,
+                            arg.expr.span(),
+                            "Gesture callback has a 'worklet' directive, but the gesture is \
+                             configured with `.runOnJS(true)` and will run its callbacks on \
+                             the JS thread",
+                        );
+                    }
+                }
+            }
+            self.in_gesture_handler_event_callback = !runs_on_js;
+
+            if !runs_on_js {
+                for arg in &mut call_expr.args {
+                    if let Expr::Ident(..) = &*arg.expr {
+                        self.process_worklet_function(&mut arg.expr, WorkletDetection::Gesture);
+                    }
+                }
+            }
+        }
+
+        call_expr.args.visit_mut_with(self);
+        call_expr.type_args.visit_mut_with(self);
+
+        self.in_gesture_handler_event_callback = old_callback;
+        self.in_run_on_js_gesture_chain = old_run_on_js;
+    }
+}
+
+/// Checks if node matches `.runOnJS(true)`.
+fn is_run_on_js_modifier(call_expr: &CallExpr) -> bool {
+    if let Callee::Expr(callee) = &call_expr.callee {
+        if let Expr::Member(MemberExpr {
+            prop: MemberProp::Ident(prop_ident),
+            ..
+        }) = &**callee
+        {
+            return &*prop_ident.sym == "runOnJS"
+                && matches!(
+                    call_expr.args.first().map(|arg| &*arg.expr),
+                    Some(Expr::Lit(Lit::Bool(Bool { value: true, .. })))
+                );
+        }
+    }
+
+    false
+}
+
+/// Checks inline style props for shared value reads,
+/// i.e. `style={{ width: width.value }}` or `style={[styles.box, { width: width.value }]}`.
+fn process_inline_styles_warning(jsx_attr: &mut JSXAttr) {
+    if !matches!(&jsx_attr.name, JSXAttrName::Ident(name) if &*name.sym == "style") {
+        return;
+    }
+
+    if let Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+        expr: JSXExpr::Expr(expr),
+        ..
+    })) = &mut jsx_attr.value
+    {
+        match &mut **expr {
+            Expr::Array(array_lit) => {
+                for element in array_lit.elems.iter_mut().flatten() {
+                    if let Expr::Object(object_lit) = &mut *element.expr {
+                        process_style_object_for_inline_styles_warning(object_lit);
+                    }
+                }
+            }
+            Expr::Object(object_lit) => {
+                process_style_object_for_inline_styles_warning(object_lit);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn process_style_object_for_inline_styles_warning(object_lit: &mut ObjectLit) {
+    for prop in &mut object_lit.props {
+        if let PropOrSpread::Prop(prop) = prop {
+            if let Prop::KeyValue(KeyValueProp { key, value }) = &mut **prop {
+                let is_transform = matches!(key, PropName::Ident(key) if &*key.sym == "transform");
+
+                match &mut **value {
+                    // transform: [{ translateX: x.value }]
+                    Expr::Array(array_lit) if is_transform => {
+                        for element in array_lit.elems.iter_mut().flatten() {
+                            if let Expr::Object(object_lit) = &mut *element.expr {
+                                process_style_object_for_inline_styles_warning(object_lit);
+                            }
+                        }
+                    }
+                    value if is_shared_value_read(value) => {
+                        *value = generate_inline_styles_warning(value.take());
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+fn is_shared_value_read(expr: &Expr) -> bool {
+    matches!(expr, Expr::Member(MemberExpr { prop: MemberProp::Ident(prop), .. }) if &*prop.sym == "value")
+}
+
+/// Replaces `x.value` with
+/// `(() => { console.warn(require('react-native-reanimated').getUseOfValueInStyleWarning()); return x.value; })()`
+fn generate_inline_styles_warning(member_expr: Expr) -> Expr {
+    let get_warning = Expr::Call(CallExpr {
+        callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
+            span: DUMMY_SP,
+            obj: Box::new(Expr::Call(CallExpr {
+                callee: Callee::Expr(Box::new(Expr::Ident(Ident::new(
+                    "require".into(),
+                    DUMMY_SP,
+                )))),
+                args: vec![ExprOrSpread {
+                    spread: None,
+                    expr: Box::new(Expr::Lit(Lit::Str(Str::from("react-native-reanimated")))),
+                }],
+                ..CallExpr::dummy()
+            })),
+            prop: MemberProp::Ident(Ident::new("getUseOfValueInStyleWarning".into(), DUMMY_SP)),
+        }))),
+        ..CallExpr::dummy()
+    });
+
+    let console_warn = Expr::Call(CallExpr {
+        callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
+            span: DUMMY_SP,
+            obj: Box::new(Expr::Ident(Ident::new("console".into(), DUMMY_SP))),
+            prop: MemberProp::Ident(Ident::new("warn".into(), DUMMY_SP)),
+        }))),
+        args: vec![ExprOrSpread {
+            spread: None,
+            expr: Box::new(get_warning),
+        }],
+        ..CallExpr::dummy()
+    });
+
+    Expr::Call(CallExpr {
+        callee: Callee::Expr(Box::new(Expr::Paren(ParenExpr {
+            span: DUMMY_SP,
+            expr: Box::new(Expr::Arrow(ArrowExpr {
+                body: BlockStmtOrExpr::BlockStmt(BlockStmt {
+                    stmts: vec![
+                        Stmt::Expr(ExprStmt {
+                            span: DUMMY_SP,
+                            expr: Box::new(console_warn),
+                        }),
+                        Stmt::Return(ReturnStmt {
+                            span: DUMMY_SP,
+                            arg: Some(Box::new(member_expr)),
+                        }),
+                    ],
+                    ..BlockStmt::dummy()
+                }),
+                ..ArrowExpr::dummy()
+            })),
+        }))),
+        ..CallExpr::dummy()
+    })
+}
+
+impl<C: Clone + swc_common::comments::Comments, S: swc_common::SourceMapper + SourceMapperExt>
+    VisitMut for ReanimatedWorkletsVisitor<C, S>
+{
+    fn visit_mut_jsx_attr(&mut self, jsx_attr: &mut JSXAttr) {
+        jsx_attr.visit_mut_children_with(self);
+
+        if self.inline_styles_warning {
+            process_inline_styles_warning(jsx_attr);
+        }
+    }
 
-impl<C: Clone + swc_common::comments::Comments, S: swc_common::SourceMapper + SourceMapperExt>
-    VisitMut for ReanimatedWorkletsVisitor<C, S>
-{
     fn visit_mut_call_expr(&mut self, call_expr: &mut CallExpr) {
-        if is_gesture_object_event_callback_method(&call_expr.callee) {
-            let old = self.in_gesture_handler_event_callback;
-            self.in_gesture_handler_event_callback =
-                is_gesture_object_event_callback_method(&mut call_expr.callee);
+        if self.is_gesture_object_chain_call(call_expr) {
+            self.process_gesture_object_chain_call(call_expr);
+        } else if self.is_layout_animation_callback_method(&call_expr.callee) {
+            self.process_layout_animation_callback(call_expr);
             call_expr.visit_mut_children_with(self);
-            self.in_gesture_handler_event_callback = old;
         } else {
             self.process_worklets(call_expr);
             call_expr.visit_mut_children_with(self);
         }
     }
 
+    fn visit_mut_module(&mut self, module: &mut Module) {
+        // Bindings are compared by `Id`, which requires the program to be resolved.
+        // Identifiers already resolved by the host are left as is.
+        module.visit_mut_with(&mut resolver(Mark::new(), Mark::new(), false));
+        self.collect_imports(module);
+        self.collect_run_on_js_gesture_bindings(module);
+        module.visit_mut_children_with(self);
+        self.process_worklet_references(module);
+        self.check_worklet_callees(module);
+    }
+
+    fn visit_mut_script(&mut self, script: &mut Script) {
+        script.visit_mut_with(&mut resolver(Mark::new(), Mark::new(), false));
+        self.collect_run_on_js_gesture_bindings(script);
+        script.visit_mut_children_with(self);
+        self.process_worklet_references(script);
+        self.check_worklet_callees(script);
+    }
+
+    fn visit_mut_var_declarator(&mut self, declarator: &mut VarDeclarator) {
+        self.collect_gesture_binding(declarator);
+        if let (Pat::Ident(BindingIdent { id, .. }), Some(init)) =
+            (&declarator.name, &declarator.init)
+        {
+            if has_worklet_directive(init) || is_worklet_factory_call(init) {
+                self.worklet_bindings.insert(id.to_id());
+            }
+        }
+
+        declarator.visit_mut_children_with(self);
+    }
+
     fn visit_mut_decl(&mut self, decl: &mut Decl) {
         decl.visit_mut_children_with(self);
 
-        match decl {
-            Decl::Fn(..) => {
-                self.process_if_fn_decl_worklet_node(decl);
-                if self.in_gesture_handler_event_callback {
-                    self.process_worklet_fn_decl(decl);
-                }
+        if let Decl::Fn(..) = decl {
+            self.process_if_fn_decl_worklet_node(decl);
+            if self.in_gesture_handler_event_callback {
+                self.process_worklet_fn_decl(decl, WorkletDetection::Gesture);
             }
-            _ => {}
         }
     }
 
     // Note we do not transform class method itself - it should be performed by core transform instead
     fn visit_mut_class_method(&mut self, class_method: &mut ClassMethod) {
-        match &mut class_method.key {
-            PropName::Ident(ident) => {
-                let mut visitor = DirectiveFinderVisitor::new(self.comments.clone());
-                class_method.function.visit_mut_children_with(&mut visitor);
-
-                // TODO: consolidate with process_if_fn_decl_worklet_node
-                if visitor.has_worklet_directive {
-                    let worklet_fn = self
-                        .make_worklet_from_fn(&mut Some(ident.clone()), &mut class_method.function);
+        if let PropName::Ident(ident) = &mut class_method.key {
+            let mut visitor = DirectiveFinderVisitor::new(self.comments.clone());
+            class_method.function.visit_mut_children_with(&mut visitor);
+
+            // TODO: consolidate with process_if_fn_decl_worklet_node
+            if visitor.has_worklet_directive {
+                if let Some(worklet_fn) = self.make_worklet_from_fn(
+                    &mut Some(ident.clone()),
+                    &mut class_method.function,
+                    WorkletDetection::Directive,
+                ) {
                     class_method.function = worklet_fn;
                 }
             }
-            _ => {}
         }
     }
 
@@ -763,7 +1759,7 @@ impl<C: Clone + swc_common::comments::Comments, S: swc_common::SourceMapper + So
             Expr::Arrow(..) | Expr::Fn(..) => {
                 self.process_if_worklet_node(expr);
                 if self.in_gesture_handler_event_callback {
-                    self.process_worklet_function(expr);
+                    self.process_worklet_function(expr, WorkletDetection::Gesture);
                 }
             }
             _ => {}
@@ -771,10 +1767,446 @@ impl<C: Clone + swc_common::comments::Comments, S: swc_common::SourceMapper + So
     }
 }
 
+/// `_f.prop = value`
+fn make_fn_prop_assign_stmt(fn_ident: &Ident, prop: Ident, value: Expr) -> Stmt {
+    Stmt::Expr(ExprStmt {
+        span: DUMMY_SP,
+        expr: Box::new(Expr::Assign(AssignExpr {
+            span: DUMMY_SP,
+            op: AssignOp::Assign,
+            left: PatOrExpr::Expr(Box::new(Expr::Member(MemberExpr {
+                span: DUMMY_SP,
+                obj: Box::new(Expr::Ident(fn_ident.clone())),
+                prop: MemberProp::Ident(prop),
+            }))),
+            right: Box::new(value),
+        })),
+    })
+}
+
+/// Checks if node is a call to a function creating worklets, i.e. `useWorkletCallback(..)`.
+fn is_worklet_factory_call(expr: &Expr) -> bool {
+    if let Expr::Call(CallExpr {
+        callee: Callee::Expr(callee),
+        ..
+    }) = expr
+    {
+        if let Some(name) = get_callee_expr_ident(callee) {
+            return WORKLET_FACTORY_FUNCTIONS.contains(&&*name.sym);
+        }
+    }
+
+    false
+}
+
+/// Collects functions declared in the module which weren't workletized:
+/// - `function foo() {}`
+/// - `const foo = () => {}`
+#[derive(Default)]
+struct LocalFunctionsCollector {
+    functions: HashSet<Id>,
+}
+
+impl Visit for LocalFunctionsCollector {
+    fn visit_fn_decl(&mut self, fn_decl: &FnDecl) {
+        self.functions.insert(fn_decl.ident.to_id());
+        fn_decl.visit_children_with(self);
+    }
+
+    fn visit_var_declarator(&mut self, declarator: &VarDeclarator) {
+        if let (Pat::Ident(BindingIdent { id, .. }), Some(init)) =
+            (&declarator.name, &declarator.init)
+        {
+            if matches!(&**init, Expr::Arrow(..) | Expr::Fn(..)) {
+                self.functions.insert(id.to_id());
+            }
+        }
+
+        declarator.visit_children_with(self);
+    }
+}
+
+/// Collects identifiers a `.runOnJS(true)` chain is called on:
+/// - `pan.runOnJS(true)`
+/// - `pan.onStart(callback).runOnJS(true)`
+#[derive(Default)]
+struct RunOnJsBindingsCollector {
+    roots: Vec<Ident>,
+}
+
+impl Visit for RunOnJsBindingsCollector {
+    fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+        if is_run_on_js_modifier(call_expr) {
+            if let Some(ident) = get_call_chain_root(call_expr) {
+                self.roots.push(ident.clone());
+            }
+        }
+
+        call_expr.visit_children_with(self);
+    }
+}
+
+/// Returns the identifier a method call chain is called on, i.e. `pan` in `pan.onStart(cb)`.
+fn get_call_chain_root(call_expr: &CallExpr) -> Option<&Ident> {
+    if let Callee::Expr(callee) = &call_expr.callee {
+        if let Expr::Member(MemberExpr { obj, .. }) = &**callee {
+            return match &**obj {
+                Expr::Ident(ident) => Some(ident),
+                Expr::Call(call_expr) => get_call_chain_root(call_expr),
+                _ => None,
+            };
+        }
+    }
+
+    None
+}
+
+fn is_directive(stmt: &Stmt) -> bool {
+    matches!(stmt, Stmt::Expr(ExprStmt { expr, .. }) if matches!(&**expr, Expr::Lit(Lit::Str(..))))
+}
+
+/// Workletizes the functions pending worklet references are bound to:
+/// - `function styleFn() {}`
+/// - `const styleFn = () => {}`
+/// - `const styleFn = function () {}`
+struct WorkletReferencesVisitor<
+    'a,
+    C: Clone + swc_common::comments::Comments,
+    S: swc_common::SourceMapper + SourceMapperExt,
+> {
+    parent: &'a mut ReanimatedWorkletsVisitor<C, S>,
+    pending: HashMap<Id, Option<i32>>,
+    resolved: HashSet<Id>,
+    referenced_above_captures: HashSet<Id>,
+}
+
+impl<'a, C: Clone + swc_common::comments::Comments, S: swc_common::SourceMapper + SourceMapperExt>
+    WorkletReferencesVisitor<'a, C, S>
+{
+    /// Workletizes referenced function declarations. Since the worklet replaces the
+    /// declaration with a `const`, it's moved up the statement list (after directives
+    /// and imports) to keep references preceding the declaration working like hoisting
+    /// did. It's never moved above the declarations it captures, as the closure is
+    /// read as soon as the worklet is created, so it isn't workletized if that leaves
+    /// it below a reference.
+    fn hoist_worklet_fn_decls<T>(
+        &mut self,
+        items: &mut Vec<T>,
+        is_leading: fn(&T) -> bool,
+        get_decl: fn(&mut T) -> Option<&mut Decl>,
+    ) where
+        T: for<'i> VisitWith<IdentUsageFinder<'i>>,
+    {
+        let leading_len = items.iter().take_while(|item| is_leading(item)).count();
+        let mut hoisted = items.drain(..leading_len).collect::<Vec<_>>();
+        // Whether each of the items in `hoisted` is a hoisted worklet.
+        let mut is_hoisted_worklet = vec![false; hoisted.len()];
+
+        for mut item in items.drain(..) {
+            let id = match get_decl(&mut item) {
+                Some(Decl::Fn(FnDecl { ident, function, .. }))
+                    if function.body.is_some() && self.pending.contains_key(&ident.to_id()) =>
+                {
+                    ident.to_id()
+                }
+                _ => {
+                    hoisted.push(item);
+                    is_hoisted_worklet.push(false);
+                    continue;
+                }
+            };
+
+            let worklet_decl = get_decl(&mut item).unwrap();
+            let mut position = hoisted
+                .iter_mut()
+                .rposition(|preceding| {
+                    get_decl(preceding).is_some_and(|preceding| {
+                        captures_binding_of(worklet_decl, preceding)
+                    })
+                })
+                .map_or(leading_len, |index| index + 1);
+            // Keeps hoisted worklets in their original order.
+            while position < hoisted.len() && is_hoisted_worklet[position] {
+                position += 1;
+            }
+
+            // The worklet can't be created before its captures are initialized, so it's
+            // left alone if it's referenced above them.
+            if hoisted[..position]
+                .iter()
+                .any(|preceding| IdentUsageFinder::find(&id, preceding))
+            {
+                self.referenced_above_captures.insert(id);
+                hoisted.push(item);
+                is_hoisted_worklet.push(false);
+                continue;
+            }
+
+            self.parent.optimization_flags = self.pending[&id];
+            self.parent
+                .process_worklet_fn_decl(get_decl(&mut item).unwrap(), WorkletDetection::Reference);
+            self.parent.optimization_flags = None;
+            self.resolved.insert(id);
+
+            hoisted.insert(position, item);
+            is_hoisted_worklet.insert(position, true);
+        }
+
+        *items = hoisted;
+    }
+}
+
+/// Whether `decl` references any of the bindings declared by `preceding`.
+fn captures_binding_of(decl: &Decl, preceding: &Decl) -> bool {
+    let ids: Vec<Id> = match preceding {
+        Decl::Fn(FnDecl { ident, .. }) | Decl::Class(ClassDecl { ident, .. }) => {
+            vec![ident.to_id()]
+        }
+        Decl::Var(var_decl) => find_pat_ids(&var_decl.decls),
+        _ => vec![],
+    };
+
+    ids.iter().any(|id| IdentUsageFinder::find(id, decl))
+}
+
+impl<'a, C: Clone + swc_common::comments::Comments, S: swc_common::SourceMapper + SourceMapperExt>
+    VisitMut for WorkletReferencesVisitor<'a, C, S>
+{
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        stmts.visit_mut_children_with(self);
+        self.hoist_worklet_fn_decls(stmts, is_directive, |stmt| match stmt {
+            Stmt::Decl(decl) => Some(decl),
+            _ => None,
+        });
+    }
+
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        items.visit_mut_children_with(self);
+        self.hoist_worklet_fn_decls(
+            items,
+            |item| match item {
+                ModuleItem::Stmt(stmt) => is_directive(stmt),
+                ModuleItem::ModuleDecl(module_decl) => module_decl.is_import(),
+            },
+            |item| match item {
+                ModuleItem::Stmt(Stmt::Decl(decl))
+                | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. })) => {
+                    Some(decl)
+                }
+                _ => None,
+            },
+        );
+    }
+
+    fn visit_mut_var_declarator(&mut self, declarator: &mut VarDeclarator) {
+        declarator.visit_mut_children_with(self);
+
+        if let (Pat::Ident(BindingIdent { id, .. }), Some(init)) =
+            (&declarator.name, &mut declarator.init)
+        {
+            let id = id.to_id();
+            if matches!(&**init, Expr::Arrow(..) | Expr::Fn(..)) {
+                if let Some(optimization_flags) = self.pending.get(&id).copied() {
+                    self.parent.optimization_flags = optimization_flags;
+                    self.parent
+                        .process_worklet_function(init, WorkletDetection::Reference);
+                    self.parent.optimization_flags = None;
+                    self.parent.worklet_bindings.insert(id.clone());
+                    self.resolved.insert(id);
+                }
+            }
+        }
+    }
+}
+
+/// Results of analyzing and stringifying a worklet, computed before the original
+/// node is modified.
+struct WorkletMetadata {
+    output: WorkletOutput,
+    opt_flags: Option<i32>,
+}
+
+/// Data attached to the generated worklet, depending on where it runs.
+enum WorkletOutput {
+    /// Everything the native runtime needs to run the worklet on the UI thread.
+    Native {
+        func_string: String,
+        func_hash: f64,
+        code_location: String,
+    },
+    /// Tags for Reanimated's Jest mocks, without the stringified code.
+    Test { func_hash: f64, code_location: String },
+    /// Web runtimes only need to recognize the worklet.
+    Web,
+}
+
+impl WorkletOutput {
+    fn func_hash(&self) -> Option<f64> {
+        match self {
+            WorkletOutput::Native { func_hash, .. } | WorkletOutput::Test { func_hash, .. } => {
+                Some(*func_hash)
+            }
+            WorkletOutput::Web => None,
+        }
+    }
+
+    fn code_location(&self) -> Option<&str> {
+        match self {
+            WorkletOutput::Native { code_location, .. }
+            | WorkletOutput::Test { code_location, .. } => Some(code_location),
+            WorkletOutput::Web => None,
+        }
+    }
+}
+
+/// How JSX inside of worklets is handled, as the UI runtime can't parse it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JsxInWorklets {
+    /// Compile JSX to `React.createElement` calls in the stringified worklet.
+    Transform,
+    /// Report a `jsx-in-worklet` diagnostic and leave the function as is,
+    /// or transform it like `Transform` when that rule is turned off.
+    #[default]
+    Error,
+}
+
+impl FromStr for JsxInWorklets {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "transform" => Ok(JsxInWorklets::Transform),
+            "error" => Ok(JsxInWorklets::Error),
+            _ => Err(format!(
+                "Unknown JSX handling `{}`, expected one of `transform`, `error`",
+                value
+            )),
+        }
+    }
+}
+
+/// How worklet strings are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum WorkletFormat {
+    /// Single line, without whitespace.
+    #[default]
+    Minified,
+    /// Indented, for reading worklets when debugging UI thread crashes.
+    Pretty,
+    /// Indented, with the comments of the original worklet.
+    PrettyWithComments,
+}
+
+impl FromStr for WorkletFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "minified" => Ok(WorkletFormat::Minified),
+            "pretty" => Ok(WorkletFormat::Pretty),
+            "pretty-with-comments" => Ok(WorkletFormat::PrettyWithComments),
+            _ => Err(format!(
+                "Unknown worklet format `{}`, expected one of `minified`, `pretty`, \
+                 `pretty-with-comments`",
+                value
+            )),
+        }
+    }
+}
+
+/// Options of the transform. Deserialized from the same JSON config by the plugin,
+/// the CLI and the fixture tests, i.e. `{ "customGlobals": ["foo"] }`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
 pub struct WorkletsOptions {
+    /// Additional globals available on the UI thread, i.e. host functions.
     pub custom_globals: Option<Vec<String>>,
+    /// File being transformed, provided by the host instead of the config.
+    #[serde(skip, default = "default_filename")]
     pub filename: FileName,
+    /// Directory `__location` paths are made relative to.
     pub relative_cwd: Option<PathBuf>,
+    /// Additional gesture types created through the `Gesture` object,
+    /// i.e. `Gesture.Foo()`.
+    pub custom_gesture_objects: Option<Vec<String>>,
+    /// Additional gesture builder methods whose callbacks should be workletized,
+    /// i.e. `Gesture.Pan().onFoo(() => {})`.
+    pub custom_gesture_builder_methods: Option<Vec<String>>,
+    /// Additional functions treated as side-effect free when calculating
+    /// `__optimalization` flags, i.e. `clamp(..)`.
+    pub custom_pure_functions: Option<Vec<String>>,
+    /// Warn about worklets calling same-module functions which aren't worklets.
+    pub check_worklet_calls: bool,
+    /// Transform for a development build, injecting runtime warnings such as for `.value`
+    /// reads in inline styles. Off by default to keep those out of release builds.
+    pub dev: bool,
+    /// Report all warnings as errors, i.e. to fail CI builds on them.
+    pub warnings_as_errors: bool,
+    /// Severity overrides by rule id, i.e. `{ "non-worklet-call": "error" }`.
+    /// Unknown rule ids fail to deserialize.
+    pub rules: Option<HashMap<Rule, Severity>>,
+    /// Don't inject runtime warnings for `.value` reads in inline styles in development builds,
+    /// i.e. `<View style={{ width: width.value }} />`.
+    pub disable_inline_styles_warning: bool,
+    /// Omit `asString`, `__workletHash`, `__location` and `__optimalization` from
+    /// worklets, marking them with `__worklet` instead. Used for web builds, where
+    /// worklets run as plain functions.
+    pub omit_native_only_data: bool,
+    /// Emit lightweight worklets for Reanimated's Jest mocks, tagged with `__workletHash`
+    /// and `__location` but without `asString`. Takes precedence over `omit_native_only_data`.
+    pub test_mode: bool,
+    /// How JSX inside of worklets is handled, reported as an error by default.
+    pub jsx_in_worklets: JsxInWorklets,
+    /// Engine worklets are stringified for, selecting the compat passes run over them.
+    pub target: WorkletsTarget,
+    /// Compat passes to run instead of the ones selected by `target`,
+    /// i.e. `["optional-chaining", "destructuring"]`.
+    pub compat_passes: Option<Vec<CompatPass>>,
+    /// Assumptions the compat passes may make, i.e. `{ "pureGetters": true }`.
+    pub assumptions: CompatAssumptions,
+    /// Re-parse each generated worklet string, reporting the worklet if it doesn't parse,
+    /// references variables outside of its closure and globals, or uses syntax one of
+    /// the compat passes should have lowered.
+    pub validate_worklets: bool,
+    /// Compress and mangle worklets with swc's minifier before they're stringified,
+    /// keeping the names of captured variables and globals.
+    pub mangle_worklets: bool,
+    /// Print worklet strings `minified`, `pretty` or `pretty-with-comments` for debugging.
+    /// The worklet hash is always calculated from the minified string.
+    pub worklet_format: WorkletFormat,
+}
+
+fn default_filename() -> FileName {
+    FileName::Anon
+}
+
+impl Default for WorkletsOptions {
+    fn default() -> Self {
+        WorkletsOptions {
+            custom_globals: None,
+            filename: default_filename(),
+            relative_cwd: None,
+            custom_gesture_objects: None,
+            custom_gesture_builder_methods: None,
+            custom_pure_functions: None,
+            check_worklet_calls: false,
+            dev: false,
+            warnings_as_errors: false,
+            rules: None,
+            disable_inline_styles_warning: false,
+            omit_native_only_data: false,
+            test_mode: false,
+            jsx_in_worklets: Default::default(),
+            target: Default::default(),
+            compat_passes: None,
+            assumptions: Default::default(),
+            validate_worklets: false,
+            mangle_worklets: false,
+            worklet_format: Default::default(),
+        }
+    }
 }
 
 impl WorkletsOptions {
@@ -787,6 +2219,7 @@ impl WorkletsOptions {
             custom_globals,
             filename,
             relative_cwd,
+            ..Default::default()
         }
     }
 }
@@ -0,0 +1,248 @@
+use std::{collections::HashSet, sync::Arc};
+
+use swc_common::{FileName, Mark, SourceMap, SyntaxContext};
+use swc_ecma_transforms_base::resolver;
+use swc_ecmascript::{
+    ast::*,
+    parser::{lexer::Lexer, EsConfig, Parser, StringInput, Syntax},
+    visit::{Visit, VisitMutWith, VisitWith},
+};
+
+use crate::utils::CompatPass;
+
+fn format_names(names: &[&String]) -> String {
+    names
+        .iter()
+        .map(|name| format!("`{}`", name))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Re-parses generated worklet strings to catch broken stringification at build time,
+/// instead of on the UI runtime.
+pub struct WorkletStringValidator<'a> {
+    globals: &'a Vec<String>,
+    /// Compat passes run for the configured target, whose syntax must not be left over.
+    passes: Vec<CompatPass>,
+}
+
+impl<'a> WorkletStringValidator<'a> {
+    pub fn new(globals: &'a Vec<String>, passes: Vec<CompatPass>) -> Self {
+        WorkletStringValidator { globals, passes }
+    }
+
+    /// Checks the worklet string parses as a single function declaration, referencing
+    /// only the variables captured from the original worklet besides globals, without
+    /// binding their names itself.
+    ///
+    /// Captures which are no longer referenced, i.e. removed as dead code, aren't reported.
+    pub fn validate(&self, func_string: &str, captured_variables: &[String]) -> Result<(), String> {
+        let mut script = self.parse(func_string)?;
+
+        // The parser accepts any syntax regardless of its target, so syntax is checked
+        // against the compat passes the worklet should have been lowered by instead.
+        let mut syntax_finder = LoweredSyntaxFinder {
+            passes: &self.passes,
+            found: None,
+        };
+        script.visit_with(&mut syntax_finder);
+        if let Some((pass, syntax)) = syntax_finder.found {
+            return Err(format!(
+                "uses {}, which the `{}` compat pass should have lowered",
+                syntax,
+                pass.id()
+            ));
+        }
+
+        // Resolves references by name, the same way the UI runtime will.
+        let unresolved_mark = Mark::new();
+        script.visit_mut_with(&mut resolver(unresolved_mark, Mark::new(), false));
+
+        let mut reference_collector = ReferenceCollector {
+            unresolved_ctxt: SyntaxContext::empty().apply_mark(unresolved_mark),
+            references: Default::default(),
+            bindings: Default::default(),
+        };
+        script.visit_with(&mut reference_collector);
+
+        let references = reference_collector
+            .references
+            .into_iter()
+            .filter(|name| !self.globals.contains(name))
+            .collect::<Vec<_>>();
+
+        let unknown_references = references
+            .iter()
+            .filter(|name| !captured_variables.contains(name))
+            .collect::<Vec<_>>();
+        if !unknown_references.is_empty() {
+            return Err(format!(
+                "references {} outside of its closure and globals",
+                format_names(&unknown_references)
+            ));
+        }
+
+        // i.e. a variable generated by the compat passes with the same name as a captured one,
+        // which every reference to the captured variable resolves to instead.
+        let shadowed_variables = captured_variables
+            .iter()
+            .filter(|name| {
+                reference_collector.bindings.contains(*name) && !references.contains(name)
+            })
+            .collect::<Vec<_>>();
+        if !shadowed_variables.is_empty() {
+            return Err(format!(
+                "shadows captured {}",
+                format_names(&shadowed_variables)
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn parse(&self, func_string: &str) -> Result<Script, String> {
+        let source_map: Arc<SourceMap> = Default::default();
+        let source_file = source_map.new_source_file(FileName::Anon, func_string.to_string());
+
+        let lexer = Lexer::new(
+            Syntax::Es(EsConfig::default()),
+            EsVersion::latest(),
+            StringInput::from(&*source_file),
+            None,
+        );
+        let mut parser = Parser::new_from(lexer);
+        let script = parser
+            .parse_script()
+            .map_err(|err| format!("doesn't parse: {}", err.kind().msg()))?;
+        if let Some(err) = parser.take_errors().into_iter().next() {
+            return Err(format!("doesn't parse: {}", err.kind().msg()));
+        }
+
+        match &*script.body {
+            [Stmt::Decl(Decl::Fn(..))] => Ok(script),
+            _ => Err("isn't a single function declaration".to_string()),
+        }
+    }
+}
+
+/// Collects the names of references which don't resolve to a binding, in order of appearance,
+/// and the names of bindings declared in the worklet string.
+struct ReferenceCollector {
+    unresolved_ctxt: SyntaxContext,
+    references: Vec<String>,
+    bindings: HashSet<String>,
+}
+
+impl Visit for ReferenceCollector {
+    fn visit_ident(&mut self, ident: &Ident) {
+        let name = ident.sym.to_string();
+        if ident.span.ctxt == self.unresolved_ctxt {
+            if !self.references.contains(&name) {
+                self.references.push(name);
+            }
+        } else if ident.span.ctxt != SyntaxContext::empty() {
+            // Bindings and the references resolving to them share the binding's context,
+            // while property names and labels keep the empty one.
+            self.bindings.insert(name);
+        }
+    }
+}
+
+/// Finds the first syntax left in the worklet string which one of the given passes lowers.
+struct LoweredSyntaxFinder<'a> {
+    passes: &'a [CompatPass],
+    found: Option<(CompatPass, &'static str)>,
+}
+
+impl LoweredSyntaxFinder<'_> {
+    fn found(&mut self, pass: CompatPass, syntax: &'static str) {
+        if self.found.is_none() && self.passes.contains(&pass) {
+            self.found = Some((pass, syntax));
+        }
+    }
+}
+
+impl Visit for LoweredSyntaxFinder<'_> {
+    fn visit_expr(&mut self, expr: &Expr) {
+        match expr {
+            Expr::Arrow(..) => self.found(CompatPass::Arrow, "an arrow function"),
+            Expr::OptChain(..) => self.found(CompatPass::OptionalChaining, "optional chaining"),
+            Expr::Tpl(..) | Expr::TaggedTpl(..) => {
+                self.found(CompatPass::TemplateLiteral, "a template literal")
+            }
+            Expr::Bin(BinExpr {
+                op: BinaryOp::NullishCoalescing,
+                ..
+            }) => self.found(CompatPass::NullishCoalescing, "nullish coalescing"),
+            Expr::Bin(BinExpr {
+                op: BinaryOp::Exp, ..
+            })
+            | Expr::Assign(AssignExpr {
+                op: AssignOp::ExpAssign,
+                ..
+            }) => self.found(CompatPass::Exponentiation, "exponentiation"),
+            _ => {}
+        }
+        expr.visit_children_with(self);
+    }
+
+    fn visit_prop(&mut self, prop: &Prop) {
+        if let Prop::Shorthand(..) = prop {
+            self.found(CompatPass::Shorthand, "a shorthand property");
+        }
+        prop.visit_children_with(self);
+    }
+
+    fn visit_class(&mut self, class: &Class) {
+        self.found(CompatPass::Classes, "a class");
+        class.visit_children_with(self);
+    }
+
+    fn visit_class_member(&mut self, member: &ClassMember) {
+        if let ClassMember::ClassProp(..)
+        | ClassMember::PrivateProp(..)
+        | ClassMember::StaticBlock(..) = member
+        {
+            self.found(CompatPass::ClassProperties, "a class field or static block");
+        }
+        member.visit_children_with(self);
+    }
+
+    fn visit_expr_or_spread(&mut self, expr_or_spread: &ExprOrSpread) {
+        if expr_or_spread.spread.is_some() {
+            self.found(CompatPass::Spread, "spread");
+        }
+        expr_or_spread.visit_children_with(self);
+    }
+
+    fn visit_prop_or_spread(&mut self, prop_or_spread: &PropOrSpread) {
+        if let PropOrSpread::Spread(..) = prop_or_spread {
+            self.found(CompatPass::ObjectRestSpread, "object spread");
+        }
+        prop_or_spread.visit_children_with(self);
+    }
+
+    fn visit_param(&mut self, param: &Param) {
+        if let Pat::Assign(..) | Pat::Rest(..) = param.pat {
+            self.found(CompatPass::Parameters, "a default or rest parameter");
+        }
+        param.visit_children_with(self);
+    }
+
+    fn visit_object_pat(&mut self, object_pat: &ObjectPat) {
+        if object_pat
+            .props
+            .iter()
+            .any(|prop| matches!(prop, ObjectPatProp::Rest(..)))
+        {
+            self.found(CompatPass::ObjectRestSpread, "object rest");
+        }
+        self.found(CompatPass::Destructuring, "destructuring");
+        object_pat.visit_children_with(self);
+    }
+
+    fn visit_array_pat(&mut self, array_pat: &ArrayPat) {
+        self.found(CompatPass::Destructuring, "destructuring");
+        array_pat.visit_children_with(self);
+    }
+}
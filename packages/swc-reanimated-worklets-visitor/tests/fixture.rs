@@ -0,0 +1,193 @@
+//! Runs the visitor over `tests/fixtures/<name>/input.{js,ts,tsx}` and compares the result with
+//! `output.js`, along with the emitted diagnostics with `output.stderr`.
+//! Options are read from `options.json` if present, in the same shape as the plugin config.
+//!
+//! Run with `UPDATE=1` to write the current outputs instead, including missing ones.
+
+use std::{
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use swc_common::{
+    comments::SingleThreadedComments, errors::Handler, errors::HANDLER, FileName,
+    FilePathMapping, Globals, SourceMap, GLOBALS,
+};
+use swc_ecmascript::{
+    codegen::{text_writer::JsWriter, Emitter},
+    parser::{lexer::Lexer, EsConfig, Parser, StringInput, Syntax, TsConfig},
+    visit::VisitMutWith,
+};
+use swc_reanimated_worklets_visitor::{create_worklets_visitor, WorkletsOptions};
+
+/// Sets the fixture's input as the file being transformed. `__location` is made relative to
+/// the fixture's directory unless `relativeCwd` is set, paths are relative to the crate to keep
+/// outputs stable across machines.
+fn set_fixture_paths(worklets_options: &mut WorkletsOptions, input: &Path, dir: &Path) {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let relative_to_manifest =
+        |path: &Path| path.strip_prefix(manifest_dir).unwrap().to_path_buf();
+
+    worklets_options.filename = FileName::Real(relative_to_manifest(input));
+    if worklets_options.relative_cwd.is_none() {
+        worklets_options.relative_cwd = Some(relative_to_manifest(dir));
+    }
+}
+
+/// Collects the diagnostics written by the handler.
+#[derive(Clone, Default)]
+struct DiagnosticsBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl Write for DiagnosticsBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+const INPUT_FILES: [&str; 3] = ["input.js", "input.ts", "input.tsx"];
+
+fn find_input(dir: &Path) -> Option<PathBuf> {
+    INPUT_FILES
+        .iter()
+        .map(|file| dir.join(file))
+        .find(|path| path.exists())
+}
+
+fn syntax_for(input: &Path) -> Syntax {
+    match input.extension().and_then(|ext| ext.to_str()) {
+        Some("ts") => Syntax::Typescript(TsConfig::default()),
+        Some("tsx") => Syntax::Typescript(TsConfig {
+            tsx: true,
+            ..Default::default()
+        }),
+        _ => Syntax::Es(EsConfig {
+            jsx: true,
+            ..Default::default()
+        }),
+    }
+}
+
+/// Returns the transformed code and the emitted diagnostics.
+fn transform(input: &Path) -> (String, String) {
+    let dir = input.parent().unwrap();
+    let input_name = input.file_name().unwrap().to_string_lossy().to_string();
+    let src = fs::read_to_string(input).unwrap();
+    let mut options = match fs::read_to_string(dir.join("options.json")) {
+        Ok(options) => serde_json::from_str::<WorkletsOptions>(&options)
+            .unwrap_or_else(|err| panic!("Invalid options in {}: {}", dir.display(), err)),
+        Err(..) => Default::default(),
+    };
+    set_fixture_paths(&mut options, input, dir);
+
+    let source_map = Arc::new(SourceMap::new(FilePathMapping::empty()));
+    let diagnostics = DiagnosticsBuffer::default();
+    let handler =
+        Handler::with_emitter_writer(Box::new(diagnostics.clone()), Some(source_map.clone()));
+    // Diagnostics point to the fixture's input, not to the absolute path on this machine.
+    let source_file = source_map.new_source_file(FileName::Custom(input_name), src);
+    let comments = SingleThreadedComments::default();
+
+    let code = GLOBALS.set(&Globals::new(), || {
+        HANDLER.set(&handler, || {
+            let lexer = Lexer::new(
+                syntax_for(input),
+                Default::default(),
+                StringInput::from(&*source_file),
+                Some(&comments),
+            );
+            let mut module = Parser::new_from(lexer)
+                .parse_module()
+                .unwrap_or_else(|err| panic!("Unable to parse {}: {:?}", dir.display(), err));
+
+            let mut visitor = create_worklets_visitor(
+                options,
+                source_map.clone(),
+                comments.clone(),
+            );
+            module.visit_mut_with(&mut visitor);
+
+            let mut buf = vec![];
+            {
+                let mut emitter = Emitter {
+                    cfg: Default::default(),
+                    cm: source_map.clone(),
+                    comments: Some(&comments),
+                    wr: JsWriter::new(source_map.clone(), "\n", &mut buf, None),
+                };
+                emitter.emit_module(&module).unwrap();
+            }
+
+            String::from_utf8(buf).unwrap()
+        })
+    });
+
+    let diagnostics = String::from_utf8(diagnostics.0.lock().unwrap().clone()).unwrap();
+    (code, diagnostics)
+}
+
+/// Compares `actual` with the content of `path`, writing it instead if updating.
+/// An empty `actual` is expected to have no file at all.
+fn compare_output(path: &Path, actual: &str, update: bool) -> Result<(), String> {
+    let expected = fs::read_to_string(path).ok();
+
+    if update {
+        if actual.is_empty() {
+            let _ = fs::remove_file(path);
+        } else {
+            fs::write(path, actual).unwrap();
+        }
+        return Ok(());
+    }
+
+    if expected.is_none() && !actual.is_empty() {
+        Err(format!("{} is missing, actual:\n{}", path.display(), actual))
+    } else if expected.as_deref().unwrap_or_default() == actual {
+        Ok(())
+    } else {
+        Err(format!(
+            "{} doesn't match:\n--- expected\n{}\n--- actual\n{}",
+            path.display(),
+            expected.as_deref().unwrap_or_default(),
+            actual
+        ))
+    }
+}
+
+#[test]
+fn fixtures() {
+    let update = std::env::var("UPDATE").is_ok_and(|value| value == "1");
+    let fixtures_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+
+    let mut dirs = fs::read_dir(&fixtures_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| find_input(path).is_some())
+        .collect::<Vec<_>>();
+    dirs.sort();
+    assert!(!dirs.is_empty(), "No fixtures found in {}", fixtures_dir.display());
+
+    let mut failures = vec![];
+    for dir in &dirs {
+        let (code, diagnostics) = transform(&find_input(dir).unwrap());
+
+        for (file, actual) in [("output.js", &code), ("output.stderr", &diagnostics)] {
+            if let Err(err) = compare_output(&dir.join(file), actual, update) {
+                failures.push(err);
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} of {} fixtures failed, run with UPDATE=1 to update them:\n\n{}",
+        failures.len(),
+        dirs.len(),
+        failures.join("\n\n")
+    );
+}
@@ -0,0 +1,4 @@
+function label(item) {
+  'worklet';
+  return item?.title ?? 'untitled';
+}
@@ -0,0 +1,11 @@
+const label = function() {
+    const _f = function _f(item) {
+        ;
+        return item?.title ?? 'untitled';
+    };
+    _f._closure = {};
+    _f.asString = 'function label(item){var ref;return(ref=item==null?void 0:item.title)!=null?ref:"untitled";}';
+    _f.__workletHash = 863086789;
+    _f.__location = "input.js (1:0)";
+    return _f;
+}();
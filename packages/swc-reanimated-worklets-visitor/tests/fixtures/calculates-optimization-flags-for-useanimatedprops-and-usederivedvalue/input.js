@@ -0,0 +1,2 @@
+const props = useAnimatedProps(() => ({ r: radius.value }));
+const derived = useDerivedValue(() => radius.value * 2);
@@ -0,0 +1,24 @@
+const props = useAnimatedProps(function() {
+    const _f = function _f() {
+        return {
+            r: radius.value
+        };
+    };
+    _f._closure = {};
+    _f.asString = "function _f(){return{r:radius.value};}";
+    _f.__workletHash = 1801526396;
+    _f.__location = "input.js (1:31)";
+    _f.__optimalization = 3;
+    return _f;
+}());
+const derived = useDerivedValue(function() {
+    const _f = function _f() {
+        return radius.value * 2;
+    };
+    _f._closure = {};
+    _f.asString = "function _f(){return radius.value*2;}";
+    _f.__workletHash = 4280047818;
+    _f.__location = "input.js (2:32)";
+    _f.__optimalization = 3;
+    return _f;
+}());
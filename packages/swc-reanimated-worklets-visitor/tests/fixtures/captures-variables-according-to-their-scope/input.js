@@ -0,0 +1,23 @@
+const offset = 10;
+
+function format(value) {
+  return value;
+}
+
+function update(items) {
+  'worklet';
+  for (let i = 0, n = items.length; i < n; i++) {
+    items[i] = offset + i;
+  }
+  const config = { margin: 1, [key]: 2 };
+  return format(config);
+
+  function format(value) {
+    return value;
+  }
+}
+
+function run(format) {
+  'worklet';
+  return format();
+}
@@ -0,0 +1,36 @@
+const offset = 10;
+function format(value) {
+    return value;
+}
+const update = function() {
+    const _f = function _f(items) {
+        ;
+        for(let i = 0, n = items.length; i < n; i++){
+            items[i] = offset + i;
+        }
+        const config = {
+            margin: 1,
+            [key]: 2
+        };
+        return format(config);
+        function format(value) {
+            return value;
+        }
+    };
+    _f._closure = {};
+    _f.asString = "function update(items){for(let i=0,n=items.length;i<n;i++){items[i]=offset+i;}const config={margin:1,[key]:2};return format(config);function format(value){return value;}}";
+    _f.__workletHash = 2897612249;
+    _f.__location = "input.js (7:0)";
+    return _f;
+}();
+const run = function() {
+    const _f = function _f(format) {
+        ;
+        return format();
+    };
+    _f._closure = {};
+    _f.asString = "function run(format){return format();}";
+    _f.__workletHash = 2677418652;
+    _f.__location = "input.js (20:0)";
+    return _f;
+}();
@@ -0,0 +1,8 @@
+function renderPrice(price) {
+  'worklet';
+  return (
+    <Text title="Tom &amp; Jerry">
+      &copy; 2022 &mdash; {price}&nbsp;&euro;
+    </Text>
+  );
+}
@@ -0,0 +1,15 @@
+const renderPrice = function() {
+    const _f = function _f(price) {
+        ;
+        return (<Text title="Tom &amp; Jerry">
+
+      © 2022 — {price} €
+
+    </Text>);
+    };
+    _f._closure = {};
+    _f.asString = 'function renderPrice(price){return React.createElement(Text,{title:"Tom & Jerry"},"\\xa9 2022 \\u2014 ",price,"\\xa0\\u20AC");}';
+    _f.__workletHash = 4195988516;
+    _f.__location = "input.js (1:0)";
+    return _f;
+}();
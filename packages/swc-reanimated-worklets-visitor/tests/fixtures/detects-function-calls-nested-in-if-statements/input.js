@@ -0,0 +1,6 @@
+const style = useAnimatedStyle(() => {
+  if (x.value) {
+    return { width: foo() };
+  }
+  return {};
+});
@@ -0,0 +1,16 @@
+const style = useAnimatedStyle(function() {
+    const _f = function _f() {
+        if (x.value) {
+            return {
+                width: foo()
+            };
+        }
+        return {};
+    };
+    _f._closure = {};
+    _f.asString = "function _f(){if(x.value){return{width:foo()};}return{};}";
+    _f.__workletHash = 2940295952;
+    _f.__location = "input.js (1:31)";
+    _f.__optimalization = 0;
+    return _f;
+}());
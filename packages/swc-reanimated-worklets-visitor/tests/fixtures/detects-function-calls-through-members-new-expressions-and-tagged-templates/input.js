@@ -0,0 +1,4 @@
+const style1 = useAnimatedStyle(() => ({ width: Math.max(1, 2) }));
+const style2 = useAnimatedStyle(() => ({ width: new Foo() }));
+const style3 = useAnimatedStyle(() => ({ width: tag`foo` }));
+const style4 = useAnimatedStyle(() => ({ width: foo?.() }));
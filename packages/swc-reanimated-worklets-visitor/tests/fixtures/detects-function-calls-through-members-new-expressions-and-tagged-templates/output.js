@@ -0,0 +1,52 @@
+const style1 = useAnimatedStyle(function() {
+    const _f = function _f() {
+        return {
+            width: Math.max(1, 2)
+        };
+    };
+    _f._closure = {};
+    _f.asString = "function _f(){return{width:Math.max(1,2)};}";
+    _f.__workletHash = 3398716951;
+    _f.__location = "input.js (1:32)";
+    _f.__optimalization = 2;
+    return _f;
+}());
+const style2 = useAnimatedStyle(function() {
+    const _f = function _f() {
+        return {
+            width: new Foo()
+        };
+    };
+    _f._closure = {};
+    _f.asString = "function _f(){return{width:new Foo};}";
+    _f.__workletHash = 2662880031;
+    _f.__location = "input.js (2:32)";
+    _f.__optimalization = 2;
+    return _f;
+}());
+const style3 = useAnimatedStyle(function() {
+    const _f = function _f() {
+        return {
+            width: tag`foo`
+        };
+    };
+    _f._closure = {};
+    _f.asString = 'function _f(){function _taggedTemplateLiteral(strings,raw){if(!raw){raw=strings.slice(0);}return Object.freeze(Object.defineProperties(strings,{raw:{value:Object.freeze(raw)}}));}function _templateObject(){const data=_taggedTemplateLiteral(["foo"]);_templateObject=function(){return data;};return data;}return{width:tag(_templateObject())};}';
+    _f.__workletHash = 1613710618;
+    _f.__location = "input.js (3:32)";
+    _f.__optimalization = 2;
+    return _f;
+}());
+const style4 = useAnimatedStyle(function() {
+    const _f = function _f() {
+        return {
+            width: foo?.()
+        };
+    };
+    _f._closure = {};
+    _f.asString = "function _f(){return{width:foo===null||foo===void 0?void 0:foo()};}";
+    _f.__workletHash = 4266690014;
+    _f.__location = "input.js (4:32)";
+    _f.__optimalization = 2;
+    return _f;
+}());
@@ -0,0 +1,4 @@
+const style = useAnimatedStyle(() => {
+  for (const item of items) {}
+  return {};
+});
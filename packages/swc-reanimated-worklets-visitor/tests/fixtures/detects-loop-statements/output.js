@@ -0,0 +1,12 @@
+const style = useAnimatedStyle(function() {
+    const _f = function _f() {
+        for (const item of items){}
+        return {};
+    };
+    _f._closure = {};
+    _f.asString = "function _f(){for(const item of items){}return{};}";
+    _f.__workletHash = 1120699332;
+    _f.__location = "input.js (1:31)";
+    _f.__optimalization = 1;
+    return _f;
+}());
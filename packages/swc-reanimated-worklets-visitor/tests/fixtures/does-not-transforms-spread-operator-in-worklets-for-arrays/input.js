@@ -0,0 +1,5 @@
+function foo() {
+  'worklet';
+  const bar = [4, 5];
+  const baz = [1, ...[2, 3], ...bar];
+}
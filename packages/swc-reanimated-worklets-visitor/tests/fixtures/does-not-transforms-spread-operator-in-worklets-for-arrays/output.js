@@ -0,0 +1,22 @@
+const foo = function() {
+    const _f = function _f() {
+        ;
+        const bar = [
+            4,
+            5
+        ];
+        const baz = [
+            1,
+            ...[
+                2,
+                3
+            ],
+            ...bar
+        ];
+    };
+    _f._closure = {};
+    _f.asString = "function foo(){const bar=[4,5];const baz=[1,...[2,3],...bar];}";
+    _f.__workletHash = 58294211;
+    _f.__location = "input.js (1:0)";
+    return _f;
+}();
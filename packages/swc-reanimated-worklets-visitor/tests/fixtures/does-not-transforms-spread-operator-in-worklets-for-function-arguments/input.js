@@ -0,0 +1,4 @@
+function foo(...args) {
+  'worklet';
+  console.log(args);
+}
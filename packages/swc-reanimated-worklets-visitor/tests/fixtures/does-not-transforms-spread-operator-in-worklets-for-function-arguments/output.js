@@ -0,0 +1,11 @@
+const foo = function() {
+    const _f = function _f(...args) {
+        ;
+        console.log(args);
+    };
+    _f._closure = {};
+    _f.asString = "function foo(...args){console.log(args);}";
+    _f.__workletHash = 3085069700;
+    _f.__location = "input.js (1:0)";
+    return _f;
+}();
@@ -0,0 +1,4 @@
+function foo(arg) {
+  'worklet';
+  console.log(...arg);
+}
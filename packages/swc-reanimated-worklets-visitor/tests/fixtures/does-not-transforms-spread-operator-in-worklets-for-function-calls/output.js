@@ -0,0 +1,11 @@
+const foo = function() {
+    const _f = function _f(arg) {
+        ;
+        console.log(...arg);
+    };
+    _f._closure = {};
+    _f.asString = "function foo(arg){console.log(...arg);}";
+    _f.__workletHash = 3932284492;
+    _f.__location = "input.js (1:0)";
+    return _f;
+}();
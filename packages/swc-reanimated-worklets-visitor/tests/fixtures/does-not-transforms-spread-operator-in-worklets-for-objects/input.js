@@ -0,0 +1,5 @@
+function foo() {
+  'worklet';
+  const bar = {d: 4, e: 5};
+  const baz = { a: 1, ...{ b: 2, c: 3 }, ...bar };
+}
@@ -0,0 +1,22 @@
+const foo = function() {
+    const _f = function _f() {
+        ;
+        const bar = {
+            d: 4,
+            e: 5
+        };
+        const baz = {
+            a: 1,
+            ...{
+                b: 2,
+                c: 3
+            },
+            ...bar
+        };
+    };
+    _f._closure = {};
+    _f.asString = "function foo(){const bar={d:4,e:5};const baz={a:1,...{b:2,c:3},...bar};}";
+    _f.__workletHash = 3390329302;
+    _f.__location = "input.js (1:0)";
+    return _f;
+}();
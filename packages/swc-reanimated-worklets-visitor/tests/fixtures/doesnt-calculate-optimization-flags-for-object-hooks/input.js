@@ -0,0 +1,3 @@
+const handler = useAnimatedScrollHandler({
+  onScroll: (event) => {},
+});
@@ -0,0 +1,10 @@
+const handler = useAnimatedScrollHandler({
+    onScroll: function() {
+        const _f = function _f(event) {};
+        _f._closure = {};
+        _f.asString = "function _f(event){}";
+        _f.__workletHash = 951356942;
+        _f.__location = "input.js (2:12)";
+        return _f;
+    }()
+});
@@ -0,0 +1,4 @@
+function f() {
+  'worklet';
+  console.log('test');
+}
@@ -0,0 +1,11 @@
+const f = function() {
+    const _f = function _f() {
+        ;
+        console.log('test');
+    };
+    _f._closure = {};
+    _f.asString = 'function f(){console.log("test");}';
+    _f.__workletHash = 2545538050;
+    _f.__location = "input.js (1:0)";
+    return _f;
+}();
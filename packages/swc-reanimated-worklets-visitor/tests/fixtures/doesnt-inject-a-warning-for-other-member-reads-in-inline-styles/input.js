@@ -0,0 +1,3 @@
+function App() {
+  return <View style={{ width: sizes.width }} />;
+}
@@ -0,0 +1,5 @@
+function App() {
+    return <View style={{
+        width: sizes.width
+    }}/>;
+}
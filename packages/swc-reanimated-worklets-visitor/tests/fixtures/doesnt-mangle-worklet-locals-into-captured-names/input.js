@@ -0,0 +1,6 @@
+function interpolate(progress) {
+  'worklet';
+  const start = a.value;
+  const end = b.value;
+  return start + (end - start) * progress;
+}
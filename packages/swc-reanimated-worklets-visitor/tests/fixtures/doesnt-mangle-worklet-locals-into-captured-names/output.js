@@ -0,0 +1,13 @@
+const interpolate = function() {
+    const _f = function _f(progress) {
+        ;
+        const start = a.value;
+        const end = b.value;
+        return start + (end - start) * progress;
+    };
+    _f._closure = {};
+    _f.asString = "function interpolate(d){let c=a.value,e=b.value;return c+(e-c)*d;}";
+    _f.__workletHash = 387589638;
+    _f.__location = "input.js (1:0)";
+    return _f;
+}();
@@ -0,0 +1,7 @@
+function update(progress) {
+  'worklet';
+  if (false) {
+    foo();
+  }
+  return progress * scale.value;
+}
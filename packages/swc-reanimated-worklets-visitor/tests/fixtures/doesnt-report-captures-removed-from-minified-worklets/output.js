@@ -0,0 +1,14 @@
+const update = function() {
+    const _f = function _f(progress) {
+        ;
+        if (false) {
+            foo();
+        }
+        return progress * scale.value;
+    };
+    _f._closure = {};
+    _f.asString = "function update(a){return a*scale.value;}";
+    _f.__workletHash = 3169600984;
+    _f.__location = "input.js (1:0)";
+    return _f;
+}();
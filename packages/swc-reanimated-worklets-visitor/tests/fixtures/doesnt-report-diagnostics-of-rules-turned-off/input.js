@@ -0,0 +1,4 @@
+import { styleFn } from './styles';
+
+const style = useAnimatedStyle(styleFn);
+const asyncStyle = useAnimatedStyle(async () => ({ width: 50 }));
@@ -0,0 +1,5 @@
+import { styleFn } from './styles';
+const style = useAnimatedStyle(styleFn);
+const asyncStyle = useAnimatedStyle(async ()=>({
+        width: 50
+    }));
@@ -0,0 +1,4 @@
+const foo = Something.Tap();
+foo.onEnd((_event, _success)=>{
+    console.log('onEnd');
+});
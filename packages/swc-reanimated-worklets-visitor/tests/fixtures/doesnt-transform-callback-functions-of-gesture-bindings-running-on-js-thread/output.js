@@ -0,0 +1,6 @@
+import { Gesture } from 'react-native-gesture-handler';
+const pan = Gesture.Pan();
+pan.runOnJS(true);
+pan.onStart((_event)=>{
+    setState(_event);
+});
@@ -0,0 +1,4 @@
+import { Gesture } from './gestures';
+const foo = Gesture.Tap().onEnd((_event, _success)=>{
+    console.log('onEnd');
+});
@@ -0,0 +1,15 @@
+import { Gesture } from 'react-native-gesture-handler';
+
+const pan = Gesture.Pan();
+pan.onStart((_event) => {
+  setState(_event);
+});
+pan.runOnJS(true);
+
+const tap = Gesture.Tap();
+tap.onStart((_event) => {
+  setState(_event);
+});
+tap.onEnd((_event) => {
+  setState(_event);
+}).runOnJS(true);
@@ -0,0 +1,9 @@
+import { FadeIn } from './animations';
+import Animated from 'react-native';
+
+const entering = FadeIn.duration(300).withCallback((finished) => {
+  console.log(finished);
+});
+const exiting = Animated.FadeOut.withCallback((finished) => {
+  console.log(finished);
+});
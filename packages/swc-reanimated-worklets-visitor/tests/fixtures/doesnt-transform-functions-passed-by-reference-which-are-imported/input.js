@@ -0,0 +1,5 @@
+import { styleFn } from './styles';
+
+function App() {
+  const style = useAnimatedStyle(styleFn);
+}
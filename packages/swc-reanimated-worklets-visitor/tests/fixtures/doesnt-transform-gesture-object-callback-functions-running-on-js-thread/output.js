@@ -0,0 +1,7 @@
+import { Gesture } from 'react-native-gesture-handler';
+const foo = Gesture.Pan().runOnJS(true).onStart((_event)=>{
+    setState(_event);
+});
+const bar = Gesture.Tap().onEnd((_event, _success)=>{
+    setState(_success);
+}).runOnJS(true);
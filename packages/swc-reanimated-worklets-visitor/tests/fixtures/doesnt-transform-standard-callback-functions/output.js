@@ -0,0 +1,3 @@
+const foo = Something.Tap().onEnd((_event, _success)=>{
+    console.log('onEnd');
+});
@@ -0,0 +1,5 @@
+function foo(x) {
+  'worklet';
+  const bar = 'worklet'; // prettier-ignore
+  const baz = "worklet"; // prettier-ignore
+}
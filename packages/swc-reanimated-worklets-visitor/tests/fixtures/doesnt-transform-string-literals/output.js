@@ -0,0 +1,12 @@
+const foo = function() {
+    const _f = function _f(x) {
+        ;
+        const bar = 'worklet'; // prettier-ignore
+        const baz = "worklet"; // prettier-ignore
+    };
+    _f._closure = {};
+    _f.asString = 'function foo(x){const bar="worklet";const baz="worklet";}';
+    _f.__workletHash = 64940805;
+    _f.__location = "input.js (1:0)";
+    return _f;
+}();
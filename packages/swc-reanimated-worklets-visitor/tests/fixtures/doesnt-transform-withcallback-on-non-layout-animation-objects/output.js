@@ -0,0 +1,3 @@
+const foo = Something.duration(300).withCallback((finished)=>{
+    console.log(finished);
+});
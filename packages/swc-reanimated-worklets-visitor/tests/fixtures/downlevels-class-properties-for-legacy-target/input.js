@@ -0,0 +1,16 @@
+function makeCounter(initial) {
+  'worklet';
+  class Counter {
+    static instances = 0;
+    static {
+      Counter.instances = 0;
+    }
+    #count = initial;
+    step = 1;
+    increment() {
+      this.#count += this.step;
+      return this.#count;
+    }
+  }
+  return new Counter();
+}
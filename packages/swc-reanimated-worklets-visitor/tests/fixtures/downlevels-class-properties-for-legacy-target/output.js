@@ -0,0 +1,23 @@
+const makeCounter = function() {
+    const _f = function _f(initial) {
+        ;
+        class Counter {
+            static instances = 0;
+            static{
+                Counter.instances = 0;
+            }
+            #count = initial;
+            step = 1;
+            increment() {
+                this.#count += this.step;
+                return this.#count;
+            }
+        }
+        return new Counter();
+    };
+    _f._closure = {};
+    _f.asString = 'function makeCounter(initial){function _checkPrivateRedeclaration(obj,privateCollection){if(privateCollection.has(obj)){throw new TypeError("Cannot initialize the same private elements twice on an object");}}function _classApplyDescriptorGet(receiver,descriptor){if(descriptor.get){return descriptor.get.call(receiver);}return descriptor.value;}function _classApplyDescriptorSet(receiver,descriptor,value){if(descriptor.set){descriptor.set.call(receiver,value);}else{if(!descriptor.writable){throw new TypeError("attempted to set read only private field");}descriptor.value=value;}}function _classCallCheck(instance,Constructor){if(!(instance instanceof Constructor)){throw new TypeError("Cannot call a class as a function");}}function _classExtractFieldDescriptor(receiver,privateMap,action){if(!privateMap.has(receiver)){throw new TypeError("attempted to "+action+" private field on non-instance");}return privateMap.get(receiver);}function _classPrivateFieldGet(receiver,privateMap){var descriptor=_classExtractFieldDescriptor(receiver,privateMap,"get");return _classApplyDescriptorGet(receiver,descriptor);}function _classPrivateFieldInit(obj,privateMap,value){_checkPrivateRedeclaration(obj,privateMap);privateMap.set(obj,value);}function _classPrivateFieldSet(receiver,privateMap,value){var descriptor=_classExtractFieldDescriptor(receiver,privateMap,"set");_classApplyDescriptorSet(receiver,descriptor,value);return value;}function _defineProperties(target,props){for(var i=0;i<props.length;i++){var descriptor=props[i];descriptor.enumerable=descriptor.enumerable||false;descriptor.configurable=true;if("value"in descriptor)descriptor.writable=true;Object.defineProperty(target,descriptor.key,descriptor);}}function _createClass(Constructor,protoProps,staticProps){if(protoProps)_defineProperties(Constructor.prototype,protoProps);if(staticProps)_defineProperties(Constructor,staticProps);return Constructor;}function _defineProperty(obj,key,value){if(key in obj){Object.defineProperty(obj,key,{value:value,enumerable:true,configurable:true,writable:true});}else{obj[key]=value;}return obj;}var _count=new WeakMap;let Counter=function(){"use strict";function Counter(){_classCallCheck(this,Counter);_classPrivateFieldInit(this,_count,{writable:true,value:initial});_defineProperty(this,"step",1);}_createClass(Counter,[{key:"increment",value:function increment(){_classPrivateFieldSet(this,_count,_classPrivateFieldGet(this,_count)+this.step);return _classPrivateFieldGet(this,_count);}}]);return Counter;}();_defineProperty(Counter,"instances",0);var __={writable:true,value:function(){Counter.instances=0;}()};return new Counter;}';
+    _f.__workletHash = 850849746;
+    _f.__location = "input.js (1:0)";
+    return _f;
+}();
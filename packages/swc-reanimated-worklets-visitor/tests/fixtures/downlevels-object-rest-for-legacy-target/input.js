@@ -0,0 +1,5 @@
+function pick(a, { b, ...rest }) {
+  'worklet';
+  const { c, ...others } = rest;
+  return [a, b, c, others];
+}
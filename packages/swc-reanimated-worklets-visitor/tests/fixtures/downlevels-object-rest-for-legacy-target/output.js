@@ -0,0 +1,17 @@
+const pick = function() {
+    const _f = function _f(a, { b , ...rest }) {
+        ;
+        const { c , ...others } = rest;
+        return [
+            a,
+            b,
+            c,
+            others
+        ];
+    };
+    _f._closure = {};
+    _f.asString = 'function pick(a,_param){function _objectWithoutProperties(source,excluded){if(source==null)return{};var target=_objectWithoutPropertiesLoose(source,excluded);var key,i;if(Object.getOwnPropertySymbols){var sourceSymbolKeys=Object.getOwnPropertySymbols(source);for(i=0;i<sourceSymbolKeys.length;i++){key=sourceSymbolKeys[i];if(excluded.indexOf(key)>=0)continue;if(!Object.prototype.propertyIsEnumerable.call(source,key))continue;target[key]=source[key];}}return target;}function _objectWithoutPropertiesLoose(source,excluded){if(source==null)return{};var target={};var sourceKeys=Object.keys(source);var key,i;for(i=0;i<sourceKeys.length;i++){key=sourceKeys[i];if(excluded.indexOf(key)>=0)continue;target[key]=source[key];}return target;}var b=_param.b,rest=_objectWithoutProperties(_param,["b"]);const c=rest.c,others=_objectWithoutProperties(rest,["c"]);return[a,b,c,others];}';
+    _f.__workletHash = 2735088991;
+    _f.__location = "input.js (1:0)";
+    return _f;
+}();
@@ -0,0 +1,4 @@
+function makeDate(parts) {
+  'worklet';
+  return new Date(...parts);
+}
@@ -0,0 +1,13 @@
+import { Base } from './base';
+
+function makeCounter(initial) {
+  'worklet';
+  class Counter extends Base {
+    #count = initial;
+    increment() {
+      this.#count += 1;
+      return this.#count;
+    }
+  }
+  return new Counter();
+}
@@ -0,0 +1,16 @@
+function area({ width, height }, ...rest) {
+  'worklet';
+  const [scale] = rest;
+  return Math.max(...[width, height]) ** 2 * scale;
+}
+
+function makePoint(x, y) {
+  'worklet';
+  class Point {
+    constructor(x, y) {
+      this.x = x;
+      this.y = y;
+    }
+  }
+  return new Point(x, y);
+}
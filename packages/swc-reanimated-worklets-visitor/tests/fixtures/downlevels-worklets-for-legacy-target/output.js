@@ -0,0 +1,32 @@
+const area = function() {
+    const _f = function _f({ width , height  }, ...rest) {
+        ;
+        const [scale] = rest;
+        return Math.max(...[
+            width,
+            height
+        ]) ** 2 * scale;
+    };
+    _f._closure = {};
+    _f.asString = 'function area(param){function _arrayLikeToArray(arr,len){if(len==null||len>arr.length)len=arr.length;for(var i=0,arr2=new Array(len);i<len;i++)arr2[i]=arr[i];return arr2;}function _arrayWithHoles(arr){if(Array.isArray(arr))return arr;}function _iterableToArrayLimit(arr,i){var _i=arr==null?null:(typeof Symbol!=="undefined"&&arr[Symbol.iterator]||arr["@@iterator"]);if(_i==null)return;var _arr=[];var _n=true;var _d=false;var _s,_e;try{for(_i=_i.call(arr);!(_n=(_s=_i.next()).done);_n=true){_arr.push(_s.value);if(i&&_arr.length===i)break;}}catch(err){_d=true;_e=err;}finally{try{if(!_n&&_i["return"]!=null)_i["return"]();}finally{if(_d)throw _e;}}return _arr;}function _nonIterableRest(){throw new TypeError("Invalid attempt to destructure non-iterable instance.\\\\nIn order to be iterable, non-array objects must have a [Symbol.iterator]() method.");}function _slicedToArray(arr,i){return _arrayWithHoles(arr)||_iterableToArrayLimit(arr,i)||_unsupportedIterableToArray(arr,i)||_nonIterableRest();}function _unsupportedIterableToArray(o,minLen){if(!o)return;if(typeof o==="string")return _arrayLikeToArray(o,minLen);var n=Object.prototype.toString.call(o).slice(8,-1);if(n==="Object"&&o.constructor)n=o.constructor.name;if(n==="Map"||n==="Set")return Array.from(n);if(n==="Arguments"||/^(?:Ui|I)nt(?:8|16|32)(?:Clamped)?Array$/.test(n))return _arrayLikeToArray(o,minLen);}let width=param.width,height=param.height;for(var _len=arguments.length,rest=new Array(_len>1?_len-1:0),_key=1;_key<_len;_key++){rest[_key-1]=arguments[_key];}var _Math;const _rest=_slicedToArray(rest,1),scale=_rest[0];return Math.pow((_Math=Math).max.apply(_Math,[width,height]),2)*scale;}';
+    _f.__workletHash = 2052404159;
+    _f.__location = "input.js (1:0)";
+    return _f;
+}();
+const makePoint = function() {
+    const _f = function _f(x, y) {
+        ;
+        class Point {
+            constructor(x, y){
+                this.x = x;
+                this.y = y;
+            }
+        }
+        return new Point(x, y);
+    };
+    _f._closure = {};
+    _f.asString = 'function makePoint(x1,y1){function _classCallCheck(instance,Constructor){if(!(instance instanceof Constructor)){throw new TypeError("Cannot call a class as a function");}}let Point=function Point(x,y){"use strict";_classCallCheck(this,Point);this.x=x;this.y=y;};return new Point(x1,y1);}';
+    _f.__workletHash = 1128329669;
+    _f.__location = "input.js (7:0)";
+    return _f;
+}();
@@ -0,0 +1,3 @@
+function App() {
+  return <View style={[styles.box, { width: width.value, transform: [{ translateX: x.value }] }]} />;
+}
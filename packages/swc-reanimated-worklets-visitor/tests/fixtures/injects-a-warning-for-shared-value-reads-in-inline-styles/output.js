@@ -0,0 +1,19 @@
+function App() {
+    return <View style={[
+        styles.box,
+        {
+            width: (()=>{
+                console.warn(require("react-native-reanimated").getUseOfValueInStyleWarning());
+                return width.value;
+            })(),
+            transform: [
+                {
+                    translateX: (()=>{
+                        console.warn(require("react-native-reanimated").getUseOfValueInStyleWarning());
+                        return x.value;
+                    })()
+                }
+            ]
+        }
+    ]}/>;
+}
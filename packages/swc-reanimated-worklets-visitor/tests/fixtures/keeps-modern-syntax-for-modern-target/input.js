@@ -0,0 +1,5 @@
+function label(item) {
+  'worklet';
+  const title = item?.title ?? `#${item.id}`;
+  return { title, render: () => title };
+}
@@ -0,0 +1,15 @@
+const label = function() {
+    const _f = function _f(item) {
+        ;
+        const title = item?.title ?? `#${item.id}`;
+        return {
+            title,
+            render: ()=>title
+        };
+    };
+    _f._closure = {};
+    _f.asString = "function label(item){const title=item?.title??`#${item.id}`;return{title,render:()=>title};}";
+    _f.__workletHash = 893902261;
+    _f.__location = "input.js (1:0)";
+    return _f;
+}();
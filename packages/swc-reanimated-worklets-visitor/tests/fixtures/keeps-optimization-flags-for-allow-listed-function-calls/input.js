@@ -0,0 +1,3 @@
+const style = useAnimatedStyle(() => ({
+  width: interpolate(x.value, [0, 1], [0, 100]),
+}));
@@ -0,0 +1,19 @@
+const style = useAnimatedStyle(function() {
+    const _f = function _f() {
+        return {
+            width: interpolate(x.value, [
+                0,
+                1
+            ], [
+                0,
+                100
+            ])
+        };
+    };
+    _f._closure = {};
+    _f.asString = "function _f(){return{width:interpolate(x.value,[0,1],[0,100])};}";
+    _f.__workletHash = 1570365152;
+    _f.__location = "input.js (1:31)";
+    _f.__optimalization = 3;
+    return _f;
+}());
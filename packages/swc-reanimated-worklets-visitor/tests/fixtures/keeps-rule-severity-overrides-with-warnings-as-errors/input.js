@@ -0,0 +1,6 @@
+import { styleFn } from './styles';
+
+function App() {
+  const style = useAnimatedStyle(styleFn);
+  const width = useDerivedValue(() => 50);
+}
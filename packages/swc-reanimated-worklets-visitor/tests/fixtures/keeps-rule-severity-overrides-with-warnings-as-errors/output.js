@@ -0,0 +1,15 @@
+import { styleFn } from './styles';
+function App() {
+    const style = useAnimatedStyle(styleFn);
+    const width = useDerivedValue(function() {
+        const _f = function _f() {
+            return 50;
+        };
+        _f._closure = {};
+        _f.asString = "function _f(){return 50;}";
+        _f.__workletHash = 4014848718;
+        _f.__location = "tests/fixtures/keeps-rule-severity-overrides-with-warnings-as-errors/input.js (5:32)";
+        _f.__optimalization = 3;
+        return _f;
+    }());
+}
@@ -0,0 +1,26 @@
+import { useAnimatedStyle } from 'react-native-reanimated';
+
+function clamp(value, min, max) {
+  'worklet';
+  const lower = Math.max(value, min);
+  return Math.min(lower, max);
+}
+
+const style = useAnimatedStyle(() => {
+  const { width = 0, height } = size.value;
+  const scale = clamp(width / height, 0, 1);
+  let a = 0;
+  for (let index = 0; index < 3; index++) {
+    a += index;
+  }
+  label: for (const item of items) {
+    if (item) continue label;
+  }
+  return { scale, offset: offset.value + a, transform: [{ scale }] };
+});
+
+function evaluate(code) {
+  'worklet';
+  const result = eval(code);
+  return result;
+}
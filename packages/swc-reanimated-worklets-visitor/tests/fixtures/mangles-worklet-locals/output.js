@@ -0,0 +1,53 @@
+import { useAnimatedStyle } from 'react-native-reanimated';
+const clamp = function() {
+    const _f = function _f(value, min, max) {
+        ;
+        const lower = Math.max(value, min);
+        return Math.min(lower, max);
+    };
+    _f._closure = {};
+    _f.asString = "function clamp(a,b,c){return Math.min(Math.max(a,b),c);}";
+    _f.__workletHash = 2461427703;
+    _f.__location = "input.js (3:0)";
+    return _f;
+}();
+const style = useAnimatedStyle(function() {
+    const _f = function _f() {
+        const { width =0 , height  } = size.value;
+        const scale = clamp(width / height, 0, 1);
+        let a = 0;
+        for(let index = 0; index < 3; index++){
+            a += index;
+        }
+        label: for (const item of items){
+            if (item) continue label;
+        }
+        return {
+            scale,
+            offset: offset.value + a,
+            transform: [
+                {
+                    scale
+                }
+            ]
+        };
+    };
+    _f._closure = {};
+    _f.asString = "function _f(){let{width:d=0,height:e}=size.value,b=clamp(d/e,0,1),c=0;for(let a=0;a<3;a++)c+=a;label:for(let f of items)if(f)continue label;return{scale:b,offset:offset.value+c,transform:[{scale:b}]};}";
+    _f.__workletHash = 4071418999;
+    _f.__location = "input.js (9:31)";
+    _f.__optimalization = 0;
+    return _f;
+}());
+const evaluate = function() {
+    const _f = function _f(code) {
+        ;
+        const result = eval(code);
+        return result;
+    };
+    _f._closure = {};
+    _f.asString = "function evaluate(code){let result=eval(code);return result;}";
+    _f.__workletHash = 3425306273;
+    _f.__location = "input.js (22:0)";
+    return _f;
+}();
@@ -0,0 +1,5 @@
+function clamp(value) {
+  return Math.min(value, 100);
+}
+
+const style = useAnimatedStyle(() => ({ width: clamp(width.value) }));
@@ -0,0 +1,13 @@
+function clamp(value) {
+    return Math.min(value, 100);
+}
+const style = useAnimatedStyle(function() {
+    const _f = function _f() {
+        return {
+            width: clamp(width.value)
+        };
+    };
+    _f._closure = {};
+    _f.__worklet = true;
+    return _f;
+}());
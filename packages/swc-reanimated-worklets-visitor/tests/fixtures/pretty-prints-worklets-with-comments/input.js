@@ -0,0 +1,11 @@
+const f = () => {
+  'worklet';
+  // some comment
+  /*
+  * other comment
+  */
+  if (offset.value > 0) {
+    return { width: offset.value * 2 };
+  }
+  return true;
+};
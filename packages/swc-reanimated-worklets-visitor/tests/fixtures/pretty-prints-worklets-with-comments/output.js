@@ -0,0 +1,19 @@
+const f = function() {
+    const _f = function _f() {
+        ;
+        // some comment
+        /*
+  * other comment
+  */ if (offset.value > 0) {
+            return {
+                width: offset.value * 2
+            };
+        }
+        return true;
+    };
+    _f._closure = {};
+    _f.asString = "function _f() {\n    // some comment\n    /*\n  * other comment\n  */ if (offset.value > 0) {\n        return {\n            width: offset.value * 2\n        };\n    }\n    return true;\n}";
+    _f.__workletHash = 1504090402;
+    _f.__location = "input.js (1:10)";
+    return _f;
+}();
@@ -0,0 +1 @@
+const style = useAnimatedStyle(async () => ({ width: 50 }));
@@ -0,0 +1,3 @@
+const style = useAnimatedStyle(async ()=>({
+        width: 50
+    }));
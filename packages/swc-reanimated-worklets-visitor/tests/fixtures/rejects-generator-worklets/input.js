@@ -0,0 +1,4 @@
+function* foo() {
+  'worklet';
+  yield 1;
+}
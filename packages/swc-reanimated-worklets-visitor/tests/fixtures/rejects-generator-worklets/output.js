@@ -0,0 +1,4 @@
+function* foo() {
+    ;
+    yield 1;
+}
@@ -0,0 +1,19 @@
+function renderLabel(label, props) {
+    ;
+    return (<>
+
+      <Text style={styles.label} numberOfLines={1} aria-label="label" selectable {...props}>
+
+        Value:
+
+        {label}
+
+      </Text>
+
+      <view />
+
+    </>);
+}
+function Box() {
+    return <Animated.View style={style}/>;
+}
@@ -0,0 +1,8 @@
+const f = () => {
+  'worklet';
+  // some comment
+  /*
+  * other comment
+  */
+  return true;
+};
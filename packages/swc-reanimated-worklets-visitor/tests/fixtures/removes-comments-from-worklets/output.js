@@ -0,0 +1,11 @@
+const f = function() {
+    const _f = function _f() {
+        ;
+        return true;
+    };
+    _f._closure = {};
+    _f.asString = "function _f(){return true;}";
+    _f.__workletHash = 590750081;
+    _f.__location = "input.js (1:10)";
+    return _f;
+}();
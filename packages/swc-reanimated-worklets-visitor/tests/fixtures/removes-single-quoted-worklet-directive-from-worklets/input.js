@@ -0,0 +1,4 @@
+function foo(x) {
+  'worklet'; // prettier-ignore
+  return x + 2;
+}
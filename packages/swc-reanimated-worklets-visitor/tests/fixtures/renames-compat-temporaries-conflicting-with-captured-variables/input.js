@@ -0,0 +1,4 @@
+function getX() {
+  'worklet';
+  return ref.current?.x;
+}
@@ -0,0 +1,11 @@
+const getX = function() {
+    const _f = function _f() {
+        ;
+        return ref.current?.x;
+    };
+    _f._closure = {};
+    _f.asString = "function getX(){var ref1;return(ref1=ref.current)===null||ref1===void 0?void 0:ref1.x;}";
+    _f.__workletHash = 1377584674;
+    _f.__location = "input.js (1:0)";
+    return _f;
+}();
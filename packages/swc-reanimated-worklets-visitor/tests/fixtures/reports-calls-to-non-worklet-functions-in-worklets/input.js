@@ -0,0 +1,19 @@
+function clamp(value) {
+  return Math.min(value, 100);
+}
+
+const log = (value) => console.log(value);
+
+function scale(value) {
+  'worklet';
+  return value * 2;
+}
+
+const style = useAnimatedStyle(() => {
+  runOnJS(log)(width.value);
+  return { width: scale(clamp(width.value)) };
+});
+
+const handler = useDerivedValue(() => {
+  log(offset.value);
+});
@@ -0,0 +1,40 @@
+function clamp(value) {
+    return Math.min(value, 100);
+}
+const log = (value)=>console.log(value);
+const scale = function() {
+    const _f = function _f(value) {
+        ;
+        return value * 2;
+    };
+    _f._closure = {};
+    _f.asString = "function scale(value){return value*2;}";
+    _f.__workletHash = 1869161798;
+    _f.__location = "input.js (7:0)";
+    return _f;
+}();
+const style = useAnimatedStyle(function() {
+    const _f = function _f() {
+        runOnJS(log)(width.value);
+        return {
+            width: scale(clamp(width.value))
+        };
+    };
+    _f._closure = {};
+    _f.asString = "function _f(){runOnJS(log)(width.value);return{width:scale(clamp(width.value))};}";
+    _f.__workletHash = 3123783074;
+    _f.__location = "input.js (12:31)";
+    _f.__optimalization = 2;
+    return _f;
+}());
+const handler = useDerivedValue(function() {
+    const _f = function _f() {
+        log(offset.value);
+    };
+    _f._closure = {};
+    _f.asString = "function _f(){log(offset.value);}";
+    _f.__workletHash = 4139773176;
+    _f.__location = "input.js (17:32)";
+    _f.__optimalization = 2;
+    return _f;
+}());
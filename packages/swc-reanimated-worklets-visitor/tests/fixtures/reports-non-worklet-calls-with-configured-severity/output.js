@@ -0,0 +1,16 @@
+function clamp(value) {
+    return Math.min(value, 100);
+}
+const style = useAnimatedStyle(function() {
+    const _f = function _f() {
+        return {
+            width: clamp(width.value)
+        };
+    };
+    _f._closure = {};
+    _f.asString = "function _f(){return{width:clamp(width.value)};}";
+    _f.__workletHash = 4239239008;
+    _f.__location = "input.js (5:31)";
+    _f.__optimalization = 2;
+    return _f;
+}());
@@ -0,0 +1,4 @@
+const getWidth = () => {
+  'worklet';
+  return this.width;
+};
@@ -0,0 +1,11 @@
+const getWidth = function() {
+    const _f = function _f() {
+        ;
+        return this.width;
+    };
+    _f._closure = {};
+    _f.asString = "function _f(){return _this.width;}";
+    _f.__workletHash = 3287500028;
+    _f.__location = "input.js (1:17)";
+    return _f;
+}();
@@ -0,0 +1,4 @@
+import { styleFn } from './styles';
+function App() {
+    const style = useAnimatedStyle(styleFn);
+}
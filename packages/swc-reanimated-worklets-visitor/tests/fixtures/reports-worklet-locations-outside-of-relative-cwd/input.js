@@ -0,0 +1,4 @@
+function foo() {
+  'worklet';
+  return 1;
+}
@@ -0,0 +1,11 @@
+const foo = function() {
+    const _f = function _f() {
+        ;
+        return 1;
+    };
+    _f._closure = {};
+    _f.asString = "function foo(){return 1;}";
+    _f.__workletHash = 1592147187;
+    _f.__location = "tests/fixtures/reports-worklet-locations-outside-of-relative-cwd/input.js (1:0)";
+    return _f;
+}();
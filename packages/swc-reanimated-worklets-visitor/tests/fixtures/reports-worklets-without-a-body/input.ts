@@ -0,0 +1,6 @@
+import { Gesture } from 'react-native-gesture-handler';
+
+const gesture = Gesture.Tap().onEnd(() => {
+  function clamp(value: number): number;
+  console.log('end');
+});
@@ -0,0 +1,12 @@
+import { Gesture } from 'react-native-gesture-handler';
+const gesture = Gesture.Tap().onEnd(function() {
+    const _f = function _f() {
+        function clamp(value: number): number;
+        console.log('end');
+    };
+    _f._closure = {};
+    _f.asString = 'function _f(){console.log("end");}';
+    _f.__workletHash = 4231456724;
+    _f.__location = "input.ts (3:36)";
+    return _f;
+}());
@@ -0,0 +1,12 @@
+const label = function() {
+    const _f = function _f(item) {
+        ;
+        const title = item?.title ?? `#${item.id}`;
+        return ()=>title ** 2;
+    };
+    _f._closure = {};
+    _f.asString = "function label(item){const title=(item===null||item===void 0?void 0:item.title)??`#${item.id}`;return()=>Math.pow(title,2);}";
+    _f.__workletHash = 2080300036;
+    _f.__location = "input.js (1:0)";
+    return _f;
+}();
@@ -0,0 +1,11 @@
+function directionOf(offset: number) {
+  'worklet';
+  enum Direction {
+    Up = 'up',
+    Down = 'down',
+  }
+  namespace Threshold {
+    export const min = 10;
+  }
+  return offset > Threshold.min ? Direction.Down : Direction.Up;
+}
@@ -0,0 +1,18 @@
+const directionOf = function() {
+    const _f = function _f(offset: number) {
+        ;
+        enum Direction {
+            Up = 'up',
+            Down = 'down'
+        }
+        module Threshold {
+            export const min = 10;
+        }
+        return offset > Threshold.min ? Direction.Down : Direction.Up;
+    };
+    _f._closure = {};
+    _f.asString = 'function directionOf(offset){let Direction;(function(Direction){Direction["Up"]="up";Direction["Down"]="down";})(Direction||(Direction={}));let Threshold1;(function(Threshold){var min=Threshold.min=10;})(Threshold1||(Threshold1={}));return offset>Threshold1.min?Direction.Down:Direction.Up;}';
+    _f.__workletHash = 2531244935;
+    _f.__location = "input.ts (1:0)";
+    return _f;
+}();
@@ -0,0 +1,6 @@
+const style = useAnimatedStyle(() => {
+  const width = (offset.value as number) * 2;
+  const height = <number>size.value;
+  const scale = ref.current!.scale;
+  return { width, height, transform: [{ scale }] } as const;
+});
@@ -0,0 +1,22 @@
+const style = useAnimatedStyle(function() {
+    const _f = function _f() {
+        const width = (offset.value as number) * 2;
+        const height = <number>size.value;
+        const scale = ref.current!.scale;
+        return {
+            width,
+            height,
+            transform: [
+                {
+                    scale
+                }
+            ]
+        } as const;
+    };
+    _f._closure = {};
+    _f.asString = "function _f(){const width=offset.value*2;const height=size.value;const scale=ref.current.scale;return{width:width,height:height,transform:[{scale:scale}]};}";
+    _f.__workletHash = 1829460117;
+    _f.__location = "input.ts (1:31)";
+    _f.__optimalization = 3;
+    return _f;
+}());
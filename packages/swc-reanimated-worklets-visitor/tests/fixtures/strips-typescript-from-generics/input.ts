@@ -0,0 +1,9 @@
+function first<T extends object>(items: Array<T>): T | undefined {
+  'worklet';
+  type Item = T;
+  interface Holder {
+    item: Item;
+  }
+  const holder = new Map<string, T>();
+  return identity<T | undefined>(items[0]);
+}
@@ -0,0 +1,16 @@
+const first = function() {
+    const _f = function _f<T extends object>(items: Array<T>): T | undefined {
+        ;
+        type Item = T;
+        interface Holder {
+            item: Item;
+        }
+        const holder = new Map<string, T>();
+        return identity(items[0]);
+    };
+    _f._closure = {};
+    _f.asString = "function first(items){;const holder=new Map;return identity(items[0]);}";
+    _f.__workletHash = 1798672949;
+    _f.__location = "input.ts (1:0)";
+    return _f;
+}();
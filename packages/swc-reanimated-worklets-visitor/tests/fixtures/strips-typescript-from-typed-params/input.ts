@@ -0,0 +1,7 @@
+function clampWidth(width: number, { max = 100 }: { max?: number } = {}, ...rest: number[]): number {
+  'worklet';
+  const limit: number = max;
+  return Math.min(width, limit);
+}
+
+const style = useAnimatedStyle((): ViewStyle => ({ width: clampWidth(offset.value) }));
@@ -0,0 +1,27 @@
+const clampWidth = function() {
+    const _f = function _f(width: number, { max =100  }: {
+        max?: number;
+    } = {}, ...rest: number[]): number {
+        ;
+        const limit: number = max;
+        return Math.min(width, limit);
+    };
+    _f._closure = {};
+    _f.asString = "function clampWidth(width,{max=100}={},...rest){const limit=max;return Math.min(width,limit);}";
+    _f.__workletHash = 2862276984;
+    _f.__location = "input.ts (1:0)";
+    return _f;
+}();
+const style = useAnimatedStyle(function() {
+    const _f = function _f(): ViewStyle {
+        return {
+            width: clampWidth(offset.value)
+        };
+    };
+    _f._closure = {};
+    _f.asString = "function _f(){return{width:clampWidth(offset.value)};}";
+    _f.__workletHash = 2762034945;
+    _f.__location = "input.ts (7:31)";
+    _f.__optimalization = 2;
+    return _f;
+}());
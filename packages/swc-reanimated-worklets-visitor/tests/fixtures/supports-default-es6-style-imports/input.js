@@ -0,0 +1,18 @@
+import * as Reanimated from 'react-native-reanimated';
+
+function Box() {
+  const offset = Reanimated.useSharedValue(0);
+
+  const animatedStyles = Reanimated.useAnimatedStyle(() => {
+    return {
+      transform: [{ translateX: offset.value * 255 }],
+    };
+  });
+
+  return (
+    <>
+      <Animated.View style={[styles.box, animatedStyles]} />
+      <Button onPress={() => (offset.value = Math.random())} title="Move" />
+    </>
+  );
+}
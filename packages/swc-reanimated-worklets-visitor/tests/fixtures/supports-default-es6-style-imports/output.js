@@ -0,0 +1,31 @@
+import * as Reanimated from 'react-native-reanimated';
+function Box() {
+    const offset = Reanimated.useSharedValue(0);
+    const animatedStyles = Reanimated.useAnimatedStyle(function() {
+        const _f = function _f() {
+            return {
+                transform: [
+                    {
+                        translateX: offset.value * 255
+                    }
+                ]
+            };
+        };
+        _f._closure = {};
+        _f.asString = "function _f(){return{transform:[{translateX:offset.value*255}]};}";
+        _f.__workletHash = 3775031239;
+        _f.__location = "input.js (6:53)";
+        _f.__optimalization = 3;
+        return _f;
+    }());
+    return (<>
+
+      <Animated.View style={[
+        styles.box,
+        animatedStyles
+    ]}/>
+
+      <Button onPress={()=>(offset.value = Math.random())} title="Move"/>
+
+    </>);
+}
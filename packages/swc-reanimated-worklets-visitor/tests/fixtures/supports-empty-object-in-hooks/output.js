@@ -0,0 +1 @@
+useAnimatedGestureHandler({});
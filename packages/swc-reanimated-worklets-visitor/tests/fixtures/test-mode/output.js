@@ -0,0 +1,14 @@
+function clamp(value) {
+    return Math.min(value, 100);
+}
+const style = useAnimatedStyle(function() {
+    const _f = function _f() {
+        return {
+            width: clamp(width.value)
+        };
+    };
+    _f._closure = {};
+    _f.__workletHash = 1052067219;
+    _f.__location = "input.js (5:31)";
+    return _f;
+}());
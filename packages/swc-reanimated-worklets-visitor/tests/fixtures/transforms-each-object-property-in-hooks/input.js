@@ -0,0 +1,5 @@
+useAnimatedGestureHandler({
+  onStart: () => {},
+  onUpdate: () => {},
+  onEnd: () => {},
+});
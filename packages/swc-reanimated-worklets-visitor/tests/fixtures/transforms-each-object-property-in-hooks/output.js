@@ -0,0 +1,26 @@
+useAnimatedGestureHandler({
+    onStart: function() {
+        const _f = function _f() {};
+        _f._closure = {};
+        _f.asString = "function _f(){}";
+        _f.__workletHash = 3702930754;
+        _f.__location = "input.js (2:11)";
+        return _f;
+    }(),
+    onUpdate: function() {
+        const _f = function _f() {};
+        _f._closure = {};
+        _f.asString = "function _f(){}";
+        _f.__workletHash = 3702930754;
+        _f.__location = "input.js (3:12)";
+        return _f;
+    }(),
+    onEnd: function() {
+        const _f = function _f() {};
+        _f._closure = {};
+        _f.asString = "function _f(){}";
+        _f.__workletHash = 3702930754;
+        _f.__location = "input.js (4:9)";
+        return _f;
+    }()
+});
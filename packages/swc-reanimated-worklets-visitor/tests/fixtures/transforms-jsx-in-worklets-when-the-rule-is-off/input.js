@@ -0,0 +1,4 @@
+function renderLabel(label) {
+  'worklet';
+  return <Text>{label}</Text>;
+}
@@ -0,0 +1,11 @@
+const renderLabel = function() {
+    const _f = function _f(label) {
+        ;
+        return <Text >{label}</Text>;
+    };
+    _f._closure = {};
+    _f.asString = "function renderLabel(label){return React.createElement(Text,null,label);}";
+    _f.__workletHash = 3480908788;
+    _f.__location = "input.js (1:0)";
+    return _f;
+}();
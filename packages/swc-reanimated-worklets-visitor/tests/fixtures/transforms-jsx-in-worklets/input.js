@@ -0,0 +1,16 @@
+function renderLabel(label, props) {
+  'worklet';
+  return (
+    <>
+      <Text style={styles.label} numberOfLines={1} aria-label="label" selectable {...props}>
+        Value:
+        {label}
+      </Text>
+      <view />
+    </>
+  );
+}
+
+function Box() {
+  return <Animated.View style={style} />;
+}
@@ -0,0 +1,26 @@
+const renderLabel = function() {
+    const _f = function _f(label, props) {
+        ;
+        return (<>
+
+      <Text style={styles.label} numberOfLines={1} aria-label="label" selectable {...props}>
+
+        Value:
+
+        {label}
+
+      </Text>
+
+      <view />
+
+    </>);
+    };
+    _f._closure = {};
+    _f.asString = 'function renderLabel(label,props){return React.createElement(React.Fragment,null,React.createElement(Text,{style:styles.label,numberOfLines:1,"aria-label":"label",selectable:true,...props},"Value:",label),React.createElement("view",null));}';
+    _f.__workletHash = 2127691037;
+    _f.__location = "input.js (1:0)";
+    return _f;
+}();
+function Box() {
+    return <Animated.View style={style}/>;
+}
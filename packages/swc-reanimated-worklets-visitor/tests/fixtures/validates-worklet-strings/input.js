@@ -0,0 +1,11 @@
+import { useAnimatedStyle } from 'react-native-reanimated';
+
+function clamp(value) {
+  'worklet';
+  return Math.min(Math.max(value, 0), 100);
+}
+
+const style = useAnimatedStyle(() => {
+  const { width = 0, ...rest } = offset.value ?? {};
+  return { width: clamp(width), opacity: rest.opacity?.value };
+});
@@ -0,0 +1,27 @@
+import { useAnimatedStyle } from 'react-native-reanimated';
+const clamp = function() {
+    const _f = function _f(value) {
+        ;
+        return Math.min(Math.max(value, 0), 100);
+    };
+    _f._closure = {};
+    _f.asString = "function clamp(value){return Math.min(Math.max(value,0),100);}";
+    _f.__workletHash = 1429282406;
+    _f.__location = "input.js (3:0)";
+    return _f;
+}();
+const style = useAnimatedStyle(function() {
+    const _f = function _f() {
+        const { width =0 , ...rest } = offset.value ?? {};
+        return {
+            width: clamp(width),
+            opacity: rest.opacity?.value
+        };
+    };
+    _f._closure = {};
+    _f.asString = "function _f(){var ref;var _value;const{width=0,...rest}=(_value=offset.value)!==null&&_value!==void 0?_value:{};return{width:clamp(width),opacity:(ref=rest.opacity)===null||ref===void 0?void 0:ref.value};}";
+    _f.__workletHash = 2665334322;
+    _f.__location = "input.js (8:31)";
+    _f.__optimalization = 2;
+    return _f;
+}());
@@ -0,0 +1,6 @@
+function App() {
+  const value = useDerivedValue(compute);
+  function compute() {
+    return 1;
+  }
+}
@@ -0,0 +1,14 @@
+function App() {
+    const compute = function() {
+        const _f = function _f() {
+            return 1;
+        };
+        _f._closure = {};
+        _f.asString = "function compute(){return 1;}";
+        _f.__workletHash = 2509091030;
+        _f.__location = "input.js (3:2)";
+        _f.__optimalization = 3;
+        return _f;
+    }();
+    const value = useDerivedValue(compute);
+}
@@ -0,0 +1,4 @@
+function App() {
+  const styleFn = () => ({ width: 50 });
+  const style = useAnimatedStyle(styleFn);
+}
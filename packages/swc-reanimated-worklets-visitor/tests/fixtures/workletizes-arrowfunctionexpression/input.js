@@ -0,0 +1,4 @@
+const foo = (x) => {
+  'worklet';
+  return x + 2;
+};
@@ -0,0 +1,11 @@
+const foo = function() {
+    const _f = function _f(x) {
+        ;
+        return x + 2;
+    };
+    _f._closure = {};
+    _f.asString = "function _f(x){return x+2;}";
+    _f.__workletHash = 3158662926;
+    _f.__location = "input.js (1:12)";
+    return _f;
+}();
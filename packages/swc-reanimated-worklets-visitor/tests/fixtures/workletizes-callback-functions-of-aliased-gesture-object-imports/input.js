@@ -0,0 +1,5 @@
+import { Gesture as G } from 'react-native-gesture-handler';
+
+const foo = G.Pan().onStart((_event) => {
+  console.log('onStart');
+});
@@ -0,0 +1,11 @@
+import { Gesture as G } from 'react-native-gesture-handler';
+const foo = G.Pan().onStart(function() {
+    const _f = function _f(_event) {
+        console.log('onStart');
+    };
+    _f._closure = {};
+    _f.asString = 'function _f(_event){console.log("onStart");}';
+    _f.__workletHash = 2816630107;
+    _f.__location = "input.js (3:28)";
+    return _f;
+}());
@@ -0,0 +1,5 @@
+import { FadeIn as Fade } from 'react-native-reanimated';
+
+const entering = Fade.duration(300).withCallback((finished) => {
+  console.log(finished);
+});
@@ -0,0 +1,11 @@
+import { FadeIn as Fade } from 'react-native-reanimated';
+const entering = Fade.duration(300).withCallback(function() {
+    const _f = function _f(finished) {
+        console.log(finished);
+    };
+    _f._closure = {};
+    _f.asString = "function _f(finished){console.log(finished);}";
+    _f.__workletHash = 1879437225;
+    _f.__location = "input.js (3:49)";
+    return _f;
+}());
@@ -0,0 +1,7 @@
+import { Gesture } from 'react-native-gesture-handler';
+
+const pan = Gesture.Pan().runOnJS(true);
+const tap = Gesture.Tap();
+tap.onEnd((_event, _success) => {
+  console.log('onEnd');
+});
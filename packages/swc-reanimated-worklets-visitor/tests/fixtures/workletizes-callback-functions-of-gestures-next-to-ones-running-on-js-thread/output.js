@@ -0,0 +1,13 @@
+import { Gesture } from 'react-native-gesture-handler';
+const pan = Gesture.Pan().runOnJS(true);
+const tap = Gesture.Tap();
+tap.onEnd(function() {
+    const _f = function _f(_event, _success) {
+        console.log('onEnd');
+    };
+    _f._closure = {};
+    _f.asString = 'function _f(_event,_success){console.log("onEnd");}';
+    _f.__workletHash = 1652342874;
+    _f.__location = "input.js (5:10)";
+    return _f;
+}());
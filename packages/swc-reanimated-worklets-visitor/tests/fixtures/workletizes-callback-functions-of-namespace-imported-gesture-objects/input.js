@@ -0,0 +1,5 @@
+import * as RNGH from 'react-native-gesture-handler';
+
+const foo = RNGH.Gesture.Hover().onStart((_event) => {
+  console.log('onStart');
+});
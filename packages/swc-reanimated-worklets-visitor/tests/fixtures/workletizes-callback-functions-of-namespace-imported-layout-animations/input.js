@@ -0,0 +1,9 @@
+import Animated from 'react-native-reanimated';
+import * as Reanimated from 'react-native-reanimated';
+
+const entering = Animated.FadeIn.duration(300).withCallback((finished) => {
+  console.log(finished);
+});
+const layout = Reanimated.Layout.springify().withCallback((finished) => {
+  console.log(finished);
+});
@@ -0,0 +1,22 @@
+import Animated from 'react-native-reanimated';
+import * as Reanimated from 'react-native-reanimated';
+const entering = Animated.FadeIn.duration(300).withCallback(function() {
+    const _f = function _f(finished) {
+        console.log(finished);
+    };
+    _f._closure = {};
+    _f.asString = "function _f(finished){console.log(finished);}";
+    _f.__workletHash = 1879437225;
+    _f.__location = "input.js (4:60)";
+    return _f;
+}());
+const layout = Reanimated.Layout.springify().withCallback(function() {
+    const _f = function _f(finished) {
+        console.log(finished);
+    };
+    _f._closure = {};
+    _f.asString = "function _f(finished){console.log(finished);}";
+    _f.__workletHash = 1879437225;
+    _f.__location = "input.js (7:58)";
+    return _f;
+}());
@@ -0,0 +1,5 @@
+const entering = new Keyframe({ 0: { opacity: 0 }, 100: { opacity: 1 } })
+  .duration(200)
+  .withCallback((finished) => {
+    console.log(finished);
+  });
@@ -0,0 +1,17 @@
+const entering = new Keyframe({
+    0: {
+        opacity: 0
+    },
+    100: {
+        opacity: 1
+    }
+}).duration(200).withCallback(function() {
+    const _f = function _f(finished) {
+        console.log(finished);
+    };
+    _f._closure = {};
+    _f.asString = "function _f(finished){console.log(finished);}";
+    _f.__workletHash = 1879437225;
+    _f.__location = "input.js (3:16)";
+    return _f;
+}());
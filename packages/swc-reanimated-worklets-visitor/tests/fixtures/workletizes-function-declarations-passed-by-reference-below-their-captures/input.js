@@ -0,0 +1,19 @@
+function App() {
+  const sv = useSharedValue(0);
+  const value = useDerivedValue(compute);
+  const style = useAnimatedStyle(styleFn);
+  function compute() {
+    return sv.value * 2;
+  }
+  function styleFn() {
+    return { width: 50 };
+  }
+}
+
+function Counter() {
+  const v = useDerivedValue(compute);
+  const k = 2;
+  function compute() {
+    return k;
+  }
+}
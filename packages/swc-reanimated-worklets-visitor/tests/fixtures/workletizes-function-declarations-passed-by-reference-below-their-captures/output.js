@@ -0,0 +1,36 @@
+function App() {
+    const styleFn = function() {
+        const _f = function _f() {
+            return {
+                width: 50
+            };
+        };
+        _f._closure = {};
+        _f.asString = "function styleFn(){return{width:50};}";
+        _f.__workletHash = 1119797128;
+        _f.__location = "input.js (8:2)";
+        _f.__optimalization = 3;
+        return _f;
+    }();
+    const sv = useSharedValue(0);
+    const compute = function() {
+        const _f = function _f() {
+            return sv.value * 2;
+        };
+        _f._closure = {};
+        _f.asString = "function compute(){return sv.value*2;}";
+        _f.__workletHash = 905883601;
+        _f.__location = "input.js (5:2)";
+        _f.__optimalization = 3;
+        return _f;
+    }();
+    const value = useDerivedValue(compute);
+    const style = useAnimatedStyle(styleFn);
+}
+function Counter() {
+    const v = useDerivedValue(compute);
+    const k = 2;
+    function compute() {
+        return k;
+    }
+}
@@ -0,0 +1,4 @@
+function foo(x) {
+  'worklet';
+  return x + 2;
+}
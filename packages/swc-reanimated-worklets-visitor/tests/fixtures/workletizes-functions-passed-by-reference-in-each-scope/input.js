@@ -0,0 +1,14 @@
+function Box() {
+  const styleFn = () => ({ width: 50 });
+  const style = useAnimatedStyle(styleFn);
+}
+
+function Circle() {
+  const styleFn = () => ({ height: 50 });
+  const style = useAnimatedStyle(styleFn);
+}
+
+function Square() {
+  const styleFn = () => ({ opacity: 1 });
+  return styleFn;
+}
@@ -0,0 +1,38 @@
+function Box() {
+    const styleFn = function() {
+        const _f = function _f() {
+            return {
+                width: 50
+            };
+        };
+        _f._closure = {};
+        _f.asString = "function _f(){return{width:50};}";
+        _f.__workletHash = 3061529706;
+        _f.__location = "input.js (2:18)";
+        _f.__optimalization = 3;
+        return _f;
+    }();
+    const style = useAnimatedStyle(styleFn);
+}
+function Circle() {
+    const styleFn = function() {
+        const _f = function _f() {
+            return {
+                height: 50
+            };
+        };
+        _f._closure = {};
+        _f.asString = "function _f(){return{height:50};}";
+        _f.__workletHash = 1655082653;
+        _f.__location = "input.js (7:18)";
+        _f.__optimalization = 3;
+        return _f;
+    }();
+    const style = useAnimatedStyle(styleFn);
+}
+function Square() {
+    const styleFn = ()=>({
+            opacity: 1
+        });
+    return styleFn;
+}
@@ -0,0 +1,6 @@
+import { Gesture } from 'react-native-gesture-handler';
+
+function handleStart(_event) {
+  console.log('onStart');
+}
+const foo = Gesture.Tap().onStart(handleStart);
@@ -0,0 +1,12 @@
+import { Gesture } from 'react-native-gesture-handler';
+const handleStart = function() {
+    const _f = function _f(_event) {
+        console.log('onStart');
+    };
+    _f._closure = {};
+    _f.asString = 'function handleStart(_event){console.log("onStart");}';
+    _f.__workletHash = 3549626502;
+    _f.__location = "input.js (3:0)";
+    return _f;
+}();
+const foo = Gesture.Tap().onStart(handleStart);
@@ -0,0 +1,6 @@
+function App() {
+  function onScroll(event) {
+    console.log(event);
+  }
+  const handler = useAnimatedScrollHandler({ onScroll });
+}
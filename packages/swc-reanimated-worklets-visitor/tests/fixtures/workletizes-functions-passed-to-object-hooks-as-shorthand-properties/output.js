@@ -0,0 +1,15 @@
+function App() {
+    const onScroll = function() {
+        const _f = function _f(event) {
+            console.log(event);
+        };
+        _f._closure = {};
+        _f.asString = "function onScroll(event){console.log(event);}";
+        _f.__workletHash = 346331552;
+        _f.__location = "input.js (2:2)";
+        return _f;
+    }();
+    const handler = useAnimatedScrollHandler({
+        onScroll
+    });
+}
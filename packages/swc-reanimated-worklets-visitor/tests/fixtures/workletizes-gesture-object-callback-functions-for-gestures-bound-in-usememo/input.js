@@ -0,0 +1,19 @@
+import { Gesture } from 'react-native-gesture-handler';
+
+function App() {
+  const tap = useMemo(() => {
+    const g = Gesture.Tap();
+    return g.numberOfTaps(2);
+  }, []);
+  tap.onEnd((_event, _success) => {
+    console.log('onEnd');
+  });
+
+  const pan = useMemo(() => {
+    const g = Gesture.Pan().runOnJS(true);
+    return g;
+  }, []);
+  pan.onStart((_event) => {
+    setState(_event);
+  });
+}
@@ -0,0 +1,8 @@
+import { Gesture } from 'react-native-gesture-handler';
+
+function App() {
+  const tap = useMemo(() => Gesture.Tap().numberOfTaps(2), []);
+  tap.onEnd((_event, _success) => {
+    console.log('onEnd');
+  });
+}
@@ -0,0 +1,14 @@
+import { Gesture } from 'react-native-gesture-handler';
+function App() {
+    const tap = useMemo(()=>Gesture.Tap().numberOfTaps(2), []);
+    tap.onEnd(function() {
+        const _f = function _f(_event, _success) {
+            console.log('onEnd');
+        };
+        _f._closure = {};
+        _f.asString = 'function _f(_event,_success){console.log("onEnd");}';
+        _f.__workletHash = 1652342874;
+        _f.__location = "input.js (5:12)";
+        return _f;
+    }());
+}
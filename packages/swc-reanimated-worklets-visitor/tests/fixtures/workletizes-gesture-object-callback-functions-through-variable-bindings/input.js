@@ -0,0 +1,10 @@
+import { Gesture } from 'react-native-gesture-handler';
+
+const pan = Gesture.Pan();
+pan
+  .onStart((_event) => {
+    console.log('onStart');
+  })
+  .onEnd((_event) => {
+    console.log('onEnd');
+  });
@@ -0,0 +1,21 @@
+import { Gesture } from 'react-native-gesture-handler';
+const pan = Gesture.Pan();
+pan.onStart(function() {
+    const _f = function _f(_event) {
+        console.log('onStart');
+    };
+    _f._closure = {};
+    _f.asString = 'function _f(_event){console.log("onStart");}';
+    _f.__workletHash = 2816630107;
+    _f.__location = "input.js (5:11)";
+    return _f;
+}()).onEnd(function() {
+    const _f = function _f(_event) {
+        console.log('onEnd');
+    };
+    _f._closure = {};
+    _f.asString = 'function _f(_event){console.log("onEnd");}';
+    _f.__workletHash = 1565781140;
+    _f.__location = "input.js (8:9)";
+    return _f;
+}());
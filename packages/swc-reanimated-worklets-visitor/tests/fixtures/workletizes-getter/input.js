@@ -0,0 +1,6 @@
+class Foo {
+  get bar() {
+    'worklet';
+    return x + 2;
+  }
+}
@@ -0,0 +1,13 @@
+class Foo {
+    get bar() {
+        const _f = function _f() {
+            ;
+            return x + 2;
+        };
+        _f._closure = {};
+        _f.asString = "function bar(){return x+2;}";
+        _f.__workletHash = 3473211496;
+        _f.__location = "input.js (2:2)";
+        return _f;
+    }
+}
@@ -0,0 +1,3 @@
+const animatedStyle = useAnimatedStyle(() => ({
+  width: 50,
+}));
@@ -0,0 +1,5 @@
+const animatedStyle = useAnimatedStyle(function foo() {
+  return {
+    width: 50,
+  };
+});
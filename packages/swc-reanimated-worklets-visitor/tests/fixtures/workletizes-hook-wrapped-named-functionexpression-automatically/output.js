@@ -0,0 +1,13 @@
+const animatedStyle = useAnimatedStyle(function() {
+    const _f = function _f() {
+        return {
+            width: 50
+        };
+    };
+    _f._closure = {};
+    _f.asString = "function foo(){return{width:50};}";
+    _f.__workletHash = 1807769345;
+    _f.__location = "input.js (1:39)";
+    _f.__optimalization = 3;
+    return _f;
+}());
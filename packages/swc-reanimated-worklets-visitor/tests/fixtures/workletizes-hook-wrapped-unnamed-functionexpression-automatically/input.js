@@ -0,0 +1,5 @@
+const animatedStyle = useAnimatedStyle(function () {
+  return {
+    width: 50,
+  };
+});
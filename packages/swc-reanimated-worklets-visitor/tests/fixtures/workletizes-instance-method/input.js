@@ -0,0 +1,6 @@
+class Foo {
+  bar(x) {
+    'worklet';
+    return x + 2;
+  }
+}
@@ -0,0 +1,8 @@
+import { FadeIn, Layout } from 'react-native-reanimated';
+
+const entering = FadeIn.duration(300).withCallback((finished) => {
+  console.log(finished);
+});
+const layout = Layout.springify().withCallback(function (finished) {
+  console.log(finished);
+});
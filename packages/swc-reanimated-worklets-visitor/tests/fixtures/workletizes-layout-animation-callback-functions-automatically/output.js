@@ -0,0 +1,21 @@
+import { FadeIn, Layout } from 'react-native-reanimated';
+const entering = FadeIn.duration(300).withCallback(function() {
+    const _f = function _f(finished) {
+        console.log(finished);
+    };
+    _f._closure = {};
+    _f.asString = "function _f(finished){console.log(finished);}";
+    _f.__workletHash = 1879437225;
+    _f.__location = "input.js (3:51)";
+    return _f;
+}());
+const layout = Layout.springify().withCallback(function() {
+    const _f = function _f(finished) {
+        console.log(finished);
+    };
+    _f._closure = {};
+    _f.asString = "function _f(finished){console.log(finished);}";
+    _f.__workletHash = 1879437225;
+    _f.__location = "input.js (6:47)";
+    return _f;
+}());
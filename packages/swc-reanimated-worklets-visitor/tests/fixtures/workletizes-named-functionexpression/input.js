@@ -0,0 +1,4 @@
+const foo = function foo(x) {
+  'worklet';
+  return x + 2;
+};
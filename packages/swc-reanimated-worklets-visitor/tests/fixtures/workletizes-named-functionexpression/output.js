@@ -0,0 +1,11 @@
+const foo = function() {
+    const _f = function _f(x) {
+        ;
+        return x + 2;
+    };
+    _f._closure = {};
+    _f.asString = "function foo(x){return x+2;}";
+    _f.__workletHash = 1538052591;
+    _f.__location = "input.js (1:12)";
+    return _f;
+}();
@@ -0,0 +1,5 @@
+useAnimatedGestureHandler({
+  onStart: function onStart(event) {
+    console.log(event);
+  },
+});
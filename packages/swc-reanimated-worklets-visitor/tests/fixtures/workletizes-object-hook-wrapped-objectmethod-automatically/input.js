@@ -0,0 +1,5 @@
+useAnimatedGestureHandler({
+  onStart(event) {
+    console.log(event);
+  },
+});
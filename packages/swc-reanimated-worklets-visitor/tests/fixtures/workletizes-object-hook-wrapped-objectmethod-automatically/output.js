@@ -0,0 +1,12 @@
+useAnimatedGestureHandler({
+    onStart: function() {
+        const _f = function _f(event) {
+            console.log(event);
+        };
+        _f._closure = {};
+        _f.asString = "function onStart(event){console.log(event);}";
+        _f.__workletHash = 1675048407;
+        _f.__location = "input.js (2:2)";
+        return _f;
+    }
+});
@@ -0,0 +1,5 @@
+useAnimatedGestureHandler({
+  onStart: function (event) {
+    console.log(event);
+  },
+});
@@ -0,0 +1,13 @@
+import { Gesture } from 'react-native-gesture-handler';
+
+const foo = Gesture.Tap()
+  .numberOfTaps(2)
+  .onBegin(() => {
+    console.log('onBegin');
+  })
+  .onStart((_event) => {
+    console.log('onStart');
+  })
+  .onEnd((_event, _success) => {
+    console.log('onEnd');
+  });
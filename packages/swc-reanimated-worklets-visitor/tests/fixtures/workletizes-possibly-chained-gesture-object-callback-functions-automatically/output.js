@@ -0,0 +1,29 @@
+import { Gesture } from 'react-native-gesture-handler';
+const foo = Gesture.Tap().numberOfTaps(2).onBegin(function() {
+    const _f = function _f() {
+        console.log('onBegin');
+    };
+    _f._closure = {};
+    _f.asString = 'function _f(){console.log("onBegin");}';
+    _f.__workletHash = 498039665;
+    _f.__location = "input.js (5:11)";
+    return _f;
+}()).onStart(function() {
+    const _f = function _f(_event) {
+        console.log('onStart');
+    };
+    _f._closure = {};
+    _f.asString = 'function _f(_event){console.log("onStart");}';
+    _f.__workletHash = 2816630107;
+    _f.__location = "input.js (8:11)";
+    return _f;
+}()).onEnd(function() {
+    const _f = function _f(_event, _success) {
+        console.log('onEnd');
+    };
+    _f._closure = {};
+    _f.asString = 'function _f(_event,_success){console.log("onEnd");}';
+    _f.__workletHash = 1652342874;
+    _f.__location = "input.js (11:9)";
+    return _f;
+}());
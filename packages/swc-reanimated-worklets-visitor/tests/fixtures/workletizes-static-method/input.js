@@ -0,0 +1,6 @@
+class Foo {
+  static bar(x) {
+    'worklet';
+    return x + 2;
+  }
+}
@@ -0,0 +1,13 @@
+class Foo {
+    static bar() {
+        const _f = function _f(x) {
+            ;
+            return x + 2;
+        };
+        _f._closure = {};
+        _f.asString = "function bar(x){return x+2;}";
+        _f.__workletHash = 3930071736;
+        _f.__location = "input.js (2:2)";
+        return _f;
+    }
+}
@@ -0,0 +1,4 @@
+const foo = function (x) {
+  'worklet';
+  return x + 2;
+};
@@ -36,7 +36,8 @@ const transformPresets: Array<
                 __dirname,
                 "../target/wasm32-wasi/debug/swc_plugin_reanimated.wasm"
               ),
-              {},
+              // Development builds, which inject runtime warnings.
+              { dev: true },
             ],
           ],
         };
@@ -219,8 +220,8 @@ describe.each(transformPresets)("fixture with %s", (_, executeTransform) => {
               const baz = \\"worklet\\";
           };
           _f._closure = {};
-          _f.asString = 'function foo(x){;const bar=\\"worklet\\";const baz=\\"worklet\\";}';
-          _f.__workletHash = 2809676670;
+          _f.asString = 'function foo(x){const bar=\\"worklet\\";const baz=\\"worklet\\";}';
+          _f.__workletHash = 64940805;
           _f.__location = \\"${process.cwd()}/jest tests fixture (2:6)\\";
           return _f;
       }();
@@ -311,8 +312,8 @@ describe.each(transformPresets)("fixture with %s", (_, executeTransform) => {
               console.log('test');
           };
           _f._closure = {};
-          _f.asString = 'function f(){;console.log(\\"test\\");}';
-          _f.__workletHash = 3585409333;
+          _f.asString = 'function f(){console.log(\\"test\\");}';
+          _f.__workletHash = 2545538050;
           _f.__location = \\"${process.cwd()}/jest tests fixture (2:6)\\";
           return _f;
       }();
@@ -341,8 +342,8 @@ describe.each(transformPresets)("fixture with %s", (_, executeTransform) => {
               return x + 2;
           };
           _f._closure = {};
-          _f.asString = \\"function foo(x){;return x+2;}\\";
-          _f.__workletHash = 3468386974;
+          _f.asString = \\"function foo(x){return x+2;}\\";
+          _f.__workletHash = 1538052591;
           _f.__location = \\"${process.cwd()}/jest tests fixture (2:6)\\";
           return _f;
       }();
@@ -369,8 +370,8 @@ describe.each(transformPresets)("fixture with %s", (_, executeTransform) => {
               return x + 2;
           };
           _f._closure = {};
-          _f.asString = \\"function _f(x){;return x+2;}\\";
-          _f.__workletHash = 3611478349;
+          _f.asString = \\"function _f(x){return x+2;}\\";
+          _f.__workletHash = 3158662926;
           _f.__location = \\"${process.cwd()}/jest tests fixture (2:18)\\";
           return _f;
       }();
@@ -397,8 +398,8 @@ describe.each(transformPresets)("fixture with %s", (_, executeTransform) => {
               return x + 2;
           };
           _f._closure = {};
-          _f.asString = \\"function _f(x){;return x+2;}\\";
-          _f.__workletHash = 3611478349;
+          _f.asString = \\"function _f(x){return x+2;}\\";
+          _f.__workletHash = 3158662926;
           _f.__location = \\"${process.cwd()}/jest tests fixture (2:18)\\";
           return _f;
       }();
@@ -425,8 +426,8 @@ describe.each(transformPresets)("fixture with %s", (_, executeTransform) => {
               return x + 2;
           };
           _f._closure = {};
-          _f.asString = \\"function foo(x){;return x+2;}\\";
-          _f.__workletHash = 3468386974;
+          _f.asString = \\"function foo(x){return x+2;}\\";
+          _f.__workletHash = 1538052591;
           _f.__location = \\"${process.cwd()}/jest tests fixture (2:18)\\";
           return _f;
       }();
@@ -461,8 +462,8 @@ describe.each(transformPresets)("fixture with %s", (_, executeTransform) => {
                   return x + 2;
               };
               _f._closure = {};
-              _f.asString = \\"function bar(x){;return x+2;}\\";
-              _f.__workletHash = 2790860375;
+              _f.asString = \\"function bar(x){return x+2;}\\";
+              _f.__workletHash = 3930071736;
               _f.__location = \\"${process.cwd()}/jest tests fixture (3:8)\\";
               return _f;
           }
@@ -493,8 +494,8 @@ describe.each(transformPresets)("fixture with %s", (_, executeTransform) => {
                   return x + 2;
               };
               _f._closure = {};
-              _f.asString = \\"function bar(x){;return x+2;}\\";
-              _f.__workletHash = 2790860375;
+              _f.asString = \\"function bar(x){return x+2;}\\";
+              _f.__workletHash = 3930071736;
               _f.__location = \\"${process.cwd()}/jest tests fixture (3:8)\\";
               return _f;
           }
@@ -525,8 +526,8 @@ describe.each(transformPresets)("fixture with %s", (_, executeTransform) => {
                   return x + 2;
               };
               _f._closure = {};
-              _f.asString = \\"function bar(){;return x+2;}\\";
-              _f.__workletHash = 4275891655;
+              _f.asString = \\"function bar(){return x+2;}\\";
+              _f.__workletHash = 3473211496;
               _f.__location = \\"${process.cwd()}/jest tests fixture (3:8)\\";
               return _f;
           }
@@ -837,6 +838,377 @@ describe.each(transformPresets)("fixture with %s", (_, executeTransform) => {
     `);
   });
 
+  it("workletizes gesture object callback functions through variable bindings", () => {
+    const input = `
+      import { Gesture } from 'react-native-gesture-handler';
+
+      const pan = Gesture.Pan();
+      pan
+        .onStart((_event) => {
+          console.log('onStart');
+        })
+        .onEnd((_event) => {
+          console.log('onEnd');
+        });
+    `;
+
+    const { code } = executeTransform(input);
+    expect(code).toMatch(/^(.*)(_f\.__workletHash(.*)){2}$/s);
+  });
+
+  it("workletizes gesture object callback functions for gestures composed in useMemo", () => {
+    const input = `
+      import { Gesture } from 'react-native-gesture-handler';
+
+      function App() {
+        const tap = useMemo(() => Gesture.Tap().numberOfTaps(2), []);
+        tap.onEnd((_event, _success) => {
+          console.log('onEnd');
+        });
+      }
+    `;
+
+    const { code } = executeTransform(input);
+    expect(code).toContain("_f.__workletHash");
+  });
+
+  it("doesn't transform callback functions of bindings not initialized with gesture objects", () => {
+    const input = `
+      const foo = Something.Tap();
+      foo.onEnd((_event, _success) => {
+        console.log('onEnd');
+      });
+    `;
+
+    const { code } = executeTransform(input);
+    expect(code).not.toContain("_f.__workletHash");
+  });
+
+  it("doesn't transform gesture object callback functions running on JS thread", () => {
+    const input = `
+      import { Gesture } from 'react-native-gesture-handler';
+
+      const foo = Gesture.Pan()
+        .runOnJS(true)
+        .onStart((_event) => {
+          setState(_event);
+        });
+      const bar = Gesture.Tap()
+        .onEnd((_event, _success) => {
+          setState(_success);
+        })
+        .runOnJS(true);
+    `;
+
+    const { code } = executeTransform(input);
+    expect(code).not.toContain("_f.__workletHash");
+  });
+
+  it("doesn't transform callback functions of gesture bindings running on JS thread", () => {
+    const input = `
+      import { Gesture } from 'react-native-gesture-handler';
+
+      const pan = Gesture.Pan();
+      pan.runOnJS(true);
+      pan.onStart((_event) => {
+        setState(_event);
+      });
+    `;
+
+    const { code } = executeTransform(input);
+    expect(code).not.toContain("_f.__workletHash");
+  });
+
+  it("workletizes callback functions of aliased gesture object imports", () => {
+    const input = `
+      import { Gesture as G } from 'react-native-gesture-handler';
+
+      const foo = G.Pan().onStart((_event) => {
+        console.log('onStart');
+      });
+    `;
+
+    const { code } = executeTransform(input);
+    expect(code).toContain("_f.__workletHash");
+  });
+
+  it("workletizes callback functions of namespace imported gesture objects", () => {
+    const input = `
+      import * as RNGH from 'react-native-gesture-handler';
+
+      const foo = RNGH.Gesture.Hover().onStart((_event) => {
+        console.log('onStart');
+      });
+    `;
+
+    const { code } = executeTransform(input);
+    expect(code).toContain("_f.__workletHash");
+  });
+
+  it("doesn't transform callback functions of Gesture imported from other modules", () => {
+    const input = `
+      import { Gesture } from './gestures';
+
+      const foo = Gesture.Tap().onEnd((_event, _success) => {
+        console.log('onEnd');
+      });
+    `;
+
+    const { code } = executeTransform(input);
+    expect(code).not.toContain("_f.__workletHash");
+  });
+
+  it("rejects async worklets", () => {
+    const input = `
+      const style = useAnimatedStyle(async () => ({ width: 50 }));
+    `;
+
+    expect(() => executeTransform(input)).toThrow(
+      /Async functions can't be worklets/
+    );
+  });
+
+  it("rejects generator worklets", () => {
+    const input = `
+      function* foo() {
+        'worklet';
+        yield 1;
+      }
+    `;
+
+    expect(() => executeTransform(input)).toThrow(
+      /Generator functions can't be worklets/
+    );
+  });
+
+  it("rejects JSX in worklets", () => {
+    const input = `
+      function renderLabel(label) {
+        'worklet';
+        return <Text>{label}</Text>;
+      }
+    `;
+
+    expect(() => executeTransform(input)).toThrow(
+      /JSX can't be used in worklets/
+    );
+  });
+
+  // Function references
+
+  it("workletizes arrow functions passed to hooks by reference", () => {
+    const input = `
+      function App() {
+        const styleFn = () => ({ width: 50 });
+        const style = useAnimatedStyle(styleFn);
+      }
+    `;
+
+    const { code } = executeTransform(input);
+    expect(code).toContain("_f.__workletHash");
+    expect(code).toContain('_f.asString = "function _f(){return({width:50});}"');
+  });
+
+  it("workletizes and hoists function declarations passed to hooks by reference", () => {
+    const input = `
+      function App() {
+        const value = useDerivedValue(compute);
+        function compute() {
+          return 1;
+        }
+      }
+    `;
+
+    const { code } = executeTransform(input);
+    expect(code).toContain('_f.asString = "function compute(){return 1;}"');
+    expect(code.indexOf("const compute")).toBeLessThan(
+      code.indexOf("useDerivedValue(compute)")
+    );
+  });
+
+  it("workletizes functions passed to object hooks as shorthand properties", () => {
+    const input = `
+      function App() {
+        function onScroll(event) {
+          console.log(event);
+        }
+        const handler = useAnimatedScrollHandler({ onScroll });
+      }
+    `;
+
+    const { code } = executeTransform(input);
+    expect(code).toContain("_f.__workletHash");
+  });
+
+  it("workletizes functions passed to gesture callbacks by reference", () => {
+    const input = `
+      import { Gesture } from 'react-native-gesture-handler';
+
+      function handleStart(_event) {
+        console.log('onStart');
+      }
+      const foo = Gesture.Tap().onStart(handleStart);
+    `;
+
+    const { code } = executeTransform(input);
+    expect(code).toContain("_f.__workletHash");
+  });
+
+  it("doesn't transform functions passed by reference which are imported", () => {
+    const input = `
+      import { styleFn } from './styles';
+
+      function App() {
+        const style = useAnimatedStyle(styleFn);
+      }
+    `;
+
+    const { code } = executeTransform(input);
+    expect(code).not.toContain("_f.__workletHash");
+  });
+
+  // Optimization flags
+
+  it("keeps optimization flags for allow-listed function calls", () => {
+    const input = `
+      const style = useAnimatedStyle(() => ({
+        width: interpolate(x.value, [0, 1], [0, 100]),
+      }));
+    `;
+
+    const { code } = executeTransform(input);
+    expect(code).toContain("_f.__optimalization = 3;");
+  });
+
+  it("detects loop statements", () => {
+    const input = `
+      const style = useAnimatedStyle(() => {
+        for (const item of items) {}
+        return {};
+      });
+    `;
+
+    const { code } = executeTransform(input);
+    expect(code).toContain("_f.__optimalization = 1;");
+  });
+
+  it("detects function calls through members, new expressions and tagged templates", () => {
+    const input = `
+      const style1 = useAnimatedStyle(() => ({ width: Math.max(1, 2) }));
+      const style2 = useAnimatedStyle(() => ({ width: new Foo() }));
+      const style3 = useAnimatedStyle(() => ({ width: tag\`foo\` }));
+      const style4 = useAnimatedStyle(() => ({ width: foo?.() }));
+    `;
+
+    const { code } = executeTransform(input);
+    expect(code).not.toContain("_f.__optimalization = 3;");
+    expect(code.match(/_f\.__optimalization = 2;/g)).toHaveLength(4);
+  });
+
+  it("detects function calls nested in if statements", () => {
+    const input = `
+      const style = useAnimatedStyle(() => {
+        if (x.value) {
+          return { width: foo() };
+        }
+        return {};
+      });
+    `;
+
+    const { code } = executeTransform(input);
+    expect(code).toContain("_f.__optimalization = 0;");
+  });
+
+  it("calculates optimization flags for useAnimatedProps and useDerivedValue", () => {
+    const input = `
+      const props = useAnimatedProps(() => ({ r: radius.value }));
+      const derived = useDerivedValue(() => radius.value * 2);
+    `;
+
+    const { code } = executeTransform(input);
+    expect(code.match(/_f\.__optimalization = 3;/g)).toHaveLength(2);
+  });
+
+  it("doesn't calculate optimization flags for object hooks", () => {
+    const input = `
+      const handler = useAnimatedScrollHandler({
+        onScroll: (event) => {},
+      });
+    `;
+
+    const { code } = executeTransform(input);
+    expect(code).not.toContain("_f.__optimalization");
+  });
+
+  // Inline styles
+
+  it("injects a warning for shared value reads in inline styles", () => {
+    const input = `
+      function App() {
+        return <View style={[styles.box, { width: width.value, transform: [{ translateX: x.value }] }]} />;
+      }
+    `;
+
+    const { code } = executeTransform(input);
+    expect(
+      code.match(/getUseOfValueInStyleWarning\(\)/g)
+    ).toHaveLength(2);
+  });
+
+  it("doesn't inject a warning for other member reads in inline styles", () => {
+    const input = `
+      function App() {
+        return <View style={{ width: sizes.width }} />;
+      }
+    `;
+
+    const { code } = executeTransform(input);
+    expect(code).not.toContain("getUseOfValueInStyleWarning");
+  });
+
+  // Layout animations
+
+  it("workletizes layout animation callback functions automatically", () => {
+    const input = `
+      import { FadeIn, Layout } from 'react-native-reanimated';
+
+      const entering = FadeIn.duration(300).withCallback((finished) => {
+        console.log(finished);
+      });
+      const layout = Layout.springify().withCallback(function (finished) {
+        console.log(finished);
+      });
+    `;
+
+    const { code } = executeTransform(input);
+    expect(code).toMatch(/^(.*)(_f\.__workletHash(.*)){2}$/s);
+    expect(code).toContain("function _f(finished){console.log(finished);}");
+  });
+
+  it("workletizes custom keyframe callback functions automatically", () => {
+    const input = `
+      const entering = new Keyframe({ 0: { opacity: 0 }, 100: { opacity: 1 } })
+        .duration(200)
+        .withCallback((finished) => {
+          console.log(finished);
+        });
+    `;
+
+    const { code } = executeTransform(input);
+    expect(code).toContain("_f.__workletHash");
+  });
+
+  it("doesn't transform withCallback on non layout animation objects", () => {
+    const input = `
+      const foo = Something.duration(300).withCallback((finished) => {
+        console.log(finished);
+      });
+    `;
+
+    const { code } = executeTransform(input);
+    expect(code).not.toContain("_f.__workletHash");
+  });
+
   // Note: plugin does not do any downlevel transform for the spread.
   // Core transform should be configured to do transform if needed.
 
@@ -870,8 +1242,8 @@ describe.each(transformPresets)("fixture with %s", (_, executeTransform) => {
               ];
           };
           _f._closure = {};
-          _f.asString = \\"function foo(){;const bar=[4,5];const baz=[1,...[2,3],...bar];}\\";
-          _f.__workletHash = 601811320;
+          _f.asString = \\"function foo(){const bar=[4,5];const baz=[1,...[2,3],...bar];}\\";
+          _f.__workletHash = 58294211;
           _f.__location = \\"${process.cwd()}/jest tests fixture (2:6)\\";
           return _f;
       }();
@@ -909,8 +1281,8 @@ describe.each(transformPresets)("fixture with %s", (_, executeTransform) => {
               };
           };
           _f._closure = {};
-          _f.asString = \\"function foo(){;const bar={d:4,e:5};const baz={a:1,...{b:2,c:3},...bar};}\\";
-          _f.__workletHash = 505904037;
+          _f.asString = \\"function foo(){const bar={d:4,e:5};const baz={a:1,...{b:2,c:3},...bar};}\\";
+          _f.__workletHash = 3390329302;
           _f.__location = \\"${process.cwd()}/jest tests fixture (2:6)\\";
           return _f;
       }();
@@ -935,8 +1307,8 @@ describe.each(transformPresets)("fixture with %s", (_, executeTransform) => {
               console.log(args);
           };
           _f._closure = {};
-          _f.asString = \\"function foo(...args){;console.log(args);}\\";
-          _f.__workletHash = 3435132605;
+          _f.asString = \\"function foo(...args){console.log(args);}\\";
+          _f.__workletHash = 3085069700;
           _f.__location = \\"${process.cwd()}/jest tests fixture (2:6)\\";
           return _f;
       }();
@@ -961,8 +1333,8 @@ describe.each(transformPresets)("fixture with %s", (_, executeTransform) => {
               console.log(...arg);
           };
           _f._closure = {};
-          _f.asString = \\"function foo(arg){;console.log(...arg);}\\";
-          _f.__workletHash = 1527344131;
+          _f.asString = \\"function foo(arg){console.log(...arg);}\\";
+          _f.__workletHash = 3932284492;
           _f.__location = \\"${process.cwd()}/jest tests fixture (2:6)\\";
           return _f;
       }();
@@ -970,3 +1342,40 @@ describe.each(transformPresets)("fixture with %s", (_, executeTransform) => {
     `);
   });
 });
+
+describe("worklets report", () => {
+  it("reports generated worklets through the custom transform", () => {
+    const input = `
+      function Box() {
+        const offset = useSharedValue(0);
+        const style = useAnimatedStyle(() => ({ width: offset.value }));
+        function foo() {
+          'worklet';
+          return 1;
+        }
+      }
+    `;
+
+    const { transformSync } = require("../index");
+    const { report } = transformSync(
+      input,
+      true,
+      Buffer.from(JSON.stringify(options))
+    );
+    const { worklets } = JSON.parse(report);
+
+    expect(worklets).toHaveLength(2);
+    expect(worklets[0]).toMatchObject({
+      name: null,
+      detection: "hook",
+      capturedVariables: ["offset"],
+      optimizationFlags: 3,
+    });
+    expect(worklets[1]).toMatchObject({
+      name: "foo",
+      detection: "directive",
+      capturedVariables: [],
+      optimizationFlags: null,
+    });
+  });
+});
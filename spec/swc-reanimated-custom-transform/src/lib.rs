@@ -13,12 +13,14 @@ use std::{env, panic::set_hook, sync::Arc};
 use backtrace::Backtrace;
 use swc::Compiler;
 use swc_common::{self, sync::Lazy, FilePathMapping, SourceMap};
-use swc_reanimated_worklets_visitor::{create_worklets_visitor, WorkletsOptions};
+use swc_reanimated_worklets_visitor::{
+    create_worklets_visitor_with_report, WorkletsOptions, WorkletsReportCollector,
+};
 
 use std::path::Path;
 
 use napi::bindgen_prelude::Buffer;
-use swc::{config::Options, TransformOutput};
+use swc::config::Options;
 use swc_common::FileName;
 use swc_ecmascript::{transforms::pass::noop, visit::as_folder};
 
@@ -62,9 +64,22 @@ impl JsCompiler {
 
 pub type ArcCompiler = Arc<Compiler>;
 
+#[napi(object)]
+pub struct ReanimatedTransformOutput {
+    pub code: String,
+    pub map: Option<String>,
+    /// JSON serialized report of the generated worklets.
+    pub report: String,
+}
+
 #[napi]
-pub fn transform_sync(s: String, _is_module: bool, opts: Buffer) -> napi::Result<TransformOutput> {
+pub fn transform_sync(
+    s: String,
+    _is_module: bool,
+    opts: Buffer,
+) -> napi::Result<ReanimatedTransformOutput> {
     let c = get_compiler();
+    let report = WorkletsReportCollector::new();
 
     let mut options: Options = get_deserialized(&opts)?;
 
@@ -90,10 +105,15 @@ pub fn transform_sync(s: String, _is_module: bool, opts: Buffer) -> napi::Result
                     handler,
                     &options,
                     |_program, comments| {
-                        as_folder(create_worklets_visitor(
-                            WorkletsOptions::new(None, filename.clone(), None),
+                        as_folder(create_worklets_visitor_with_report(
+                            WorkletsOptions {
+                                // Development builds, which inject runtime warnings.
+                                dev: true,
+                                ..WorkletsOptions::new(None, filename.clone(), None)
+                            },
                             c.cm.clone(),
                             comments.clone(),
+                            report.clone(),
                         ))
                     },
                     |_, _| noop(),
@@ -101,5 +121,12 @@ pub fn transform_sync(s: String, _is_module: bool, opts: Buffer) -> napi::Result
             })
         },
     )
+    .and_then(|output| {
+        Ok(ReanimatedTransformOutput {
+            code: output.code,
+            map: output.map,
+            report: serde_json::to_string(&report.take())?,
+        })
+    })
     .convert_err()
 }